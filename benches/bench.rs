@@ -1,45 +1,215 @@
-#![feature(test)]
+//! Compare `AssocExt`, `AssocSortedExt`, `HashMap`, and `BTreeMap` across map sizes.
+//!
+//! Run with `cargo bench`. Each group measures one operation at sizes from 8 to 1024 pairs, so
+//! the reports show where the linear search starts losing to the std maps.
+use std::collections::{BTreeMap, HashMap};
 
-extern crate test;
+use assoc::{AssocExt, AssocSortedExt};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
 
-#[cfg(test)]
-mod tests {
-    use assoc::AssocExt;
-    use std::collections::{BTreeMap, HashMap};
-    use test::{black_box, Bencher};
+const SIZES: [u64; 8] = [8, 16, 32, 64, 128, 256, 512, 1024];
 
-    const ITERATIONS: u128 = 40;
+/// Spread the keys out so that sorted and hashed maps don't see them in order.
+fn key(i: u64) -> u64 {
+    i.wrapping_mul(0x9e37_79b9_7f4a_7c15)
+}
+
+fn vec_map(n: u64) -> Vec<(u64, u64)> {
+    (0..n).map(|i| (key(i), i)).collect()
+}
 
-    #[bench]
-    fn bench_vec(b: &mut Bencher) {
-        let mut map = Vec::new();
+fn sorted_map(n: u64) -> Vec<(u64, u64)> {
+    let mut map = vec_map(n);
+    map.sort_unstable_by_key(|&(k, _)| k);
+    map
+}
 
-        b.iter(|| {
-            for i in 0..ITERATIONS {
-                black_box(map.entry(i).or_insert(2 * i));
-            }
+fn bench_insert(c: &mut Criterion) {
+    let mut group = c.benchmark_group("insert");
+    for n in SIZES {
+        group.bench_with_input(BenchmarkId::new("vec", n), &n, |b, &n| {
+            b.iter(|| {
+                let mut map = Vec::new();
+                for i in 0..n {
+                    AssocExt::insert(&mut map, key(i), i);
+                }
+                map
+            });
+        });
+        group.bench_with_input(BenchmarkId::new("sorted", n), &n, |b, &n| {
+            b.iter(|| {
+                let mut map = Vec::new();
+                for i in 0..n {
+                    AssocSortedExt::insert(&mut map, key(i), i);
+                }
+                map
+            });
+        });
+        group.bench_with_input(BenchmarkId::new("hashmap", n), &n, |b, &n| {
+            b.iter(|| {
+                let mut map = HashMap::new();
+                for i in 0..n {
+                    map.insert(key(i), i);
+                }
+                map
+            });
+        });
+        group.bench_with_input(BenchmarkId::new("btreemap", n), &n, |b, &n| {
+            b.iter(|| {
+                let mut map = BTreeMap::new();
+                for i in 0..n {
+                    map.insert(key(i), i);
+                }
+                map
+            });
         });
     }
+    group.finish();
+}
 
-    #[bench]
-    fn bench_hashmap(b: &mut Bencher) {
-        let mut map = HashMap::new();
+fn bench_get(c: &mut Criterion) {
+    let mut group = c.benchmark_group("get");
+    for n in SIZES {
+        let vec = vec_map(n);
+        let sorted = sorted_map(n);
+        let hash: HashMap<u64, u64> = vec_map(n).into_iter().collect();
+        let btree: BTreeMap<u64, u64> = vec_map(n).into_iter().collect();
+        group.bench_function(BenchmarkId::new("vec", n), |b| {
+            b.iter(|| {
+                (0..n)
+                    .filter_map(|i| AssocExt::get(&vec, &key(i)))
+                    .sum::<u64>()
+            });
+        });
+        group.bench_function(BenchmarkId::new("sorted", n), |b| {
+            b.iter(|| {
+                (0..n)
+                    .filter_map(|i| AssocSortedExt::get(&sorted, &key(i)))
+                    .sum::<u64>()
+            });
+        });
+        group.bench_function(BenchmarkId::new("hashmap", n), |b| {
+            b.iter(|| (0..n).filter_map(|i| hash.get(&key(i))).sum::<u64>());
+        });
+        group.bench_function(BenchmarkId::new("btreemap", n), |b| {
+            b.iter(|| (0..n).filter_map(|i| btree.get(&key(i))).sum::<u64>());
+        });
+    }
+    group.finish();
+}
 
-        b.iter(|| {
-            for i in 0..ITERATIONS {
-                black_box(map.entry(i).or_insert(2 * i));
-            }
+/// Look up a few hot keys from the back of the map, where move-to-front helps the most.
+fn bench_get_skewed(c: &mut Criterion) {
+    let mut group = c.benchmark_group("get_skewed");
+    for n in SIZES {
+        let hot: Vec<u64> = (n - 4..n).map(key).collect();
+        group.bench_function(BenchmarkId::new("get", n), |b| {
+            let map = vec_map(n);
+            b.iter(|| {
+                (0..n)
+                    .filter_map(|i| AssocExt::get(&map, &hot[i as usize % hot.len()]))
+                    .sum::<u64>()
+            });
+        });
+        group.bench_function(BenchmarkId::new("get_promote", n), |b| {
+            let mut map = vec_map(n);
+            b.iter(|| {
+                (0..n)
+                    .filter_map(|i| map.get_promote(&hot[i as usize % hot.len()]).copied())
+                    .sum::<u64>()
+            });
         });
     }
+    group.finish();
+}
 
-    #[bench]
-    fn bench_btreemap(b: &mut Bencher) {
-        let mut map = BTreeMap::new();
+fn bench_remove(c: &mut Criterion) {
+    let mut group = c.benchmark_group("remove");
+    for n in SIZES {
+        group.bench_with_input(BenchmarkId::new("vec", n), &n, |b, &n| {
+            b.iter_batched_ref(
+                || vec_map(n),
+                |map| {
+                    for i in 0..n {
+                        black_box(AssocExt::remove(map, &key(i)));
+                    }
+                },
+                criterion::BatchSize::SmallInput,
+            );
+        });
+        group.bench_with_input(BenchmarkId::new("vec_shift", n), &n, |b, &n| {
+            b.iter_batched_ref(
+                || vec_map(n),
+                |map| {
+                    for i in 0..n {
+                        black_box(map.shift_remove(&key(i)));
+                    }
+                },
+                criterion::BatchSize::SmallInput,
+            );
+        });
+        group.bench_with_input(BenchmarkId::new("sorted", n), &n, |b, &n| {
+            b.iter_batched_ref(
+                || sorted_map(n),
+                |map| {
+                    for i in 0..n {
+                        black_box(AssocSortedExt::remove(map, &key(i)));
+                    }
+                },
+                criterion::BatchSize::SmallInput,
+            );
+        });
+        group.bench_with_input(BenchmarkId::new("hashmap", n), &n, |b, &n| {
+            b.iter_batched_ref(
+                || vec_map(n).into_iter().collect::<HashMap<_, _>>(),
+                |map| {
+                    for i in 0..n {
+                        black_box(map.remove(&key(i)));
+                    }
+                },
+                criterion::BatchSize::SmallInput,
+            );
+        });
+        group.bench_with_input(BenchmarkId::new("btreemap", n), &n, |b, &n| {
+            b.iter_batched_ref(
+                || vec_map(n).into_iter().collect::<BTreeMap<_, _>>(),
+                |map| {
+                    for i in 0..n {
+                        black_box(map.remove(&key(i)));
+                    }
+                },
+                criterion::BatchSize::SmallInput,
+            );
+        });
+    }
+    group.finish();
+}
 
-        b.iter(|| {
-            for i in 0..ITERATIONS {
-                black_box(map.entry(i).or_insert(2 * i));
-            }
+fn bench_iter(c: &mut Criterion) {
+    let mut group = c.benchmark_group("iter");
+    for n in SIZES {
+        let vec = vec_map(n);
+        let hash: HashMap<u64, u64> = vec_map(n).into_iter().collect();
+        let btree: BTreeMap<u64, u64> = vec_map(n).into_iter().collect();
+        group.bench_function(BenchmarkId::new("vec", n), |b| {
+            b.iter(|| vec.values().sum::<u64>());
+        });
+        group.bench_function(BenchmarkId::new("hashmap", n), |b| {
+            b.iter(|| hash.values().sum::<u64>());
+        });
+        group.bench_function(BenchmarkId::new("btreemap", n), |b| {
+            b.iter(|| btree.values().sum::<u64>());
         });
     }
+    group.finish();
 }
+
+criterion_group!(
+    benches,
+    bench_insert,
+    bench_get,
+    bench_get_skewed,
+    bench_remove,
+    bench_iter
+);
+criterion_main!(benches);
@@ -0,0 +1,38 @@
+//! The [`Equivalent`] trait for looking up keys with a different type.
+use core::borrow::Borrow;
+
+/// Key equivalence used by lookups such as [`AssocExt::get`][crate::AssocExt::get].
+///
+/// Any `Q: PartialEq` can look up keys of type `K` when `K: Borrow<Q>`, so `&str` can look up
+/// `String` keys.
+/// Implement this trait for your own types to compare against keys that cannot be borrowed as
+/// your type, such as a pair of string slices looking up a pair of owned strings:
+///
+/// ```rust
+/// use assoc::{AssocExt, Equivalent};
+///
+/// struct Name<'a>(&'a str, &'a str);
+///
+/// impl Equivalent<(String, String)> for Name<'_> {
+///     fn equivalent(&self, key: &(String, String)) -> bool {
+///         self.0 == key.0 && self.1 == key.1
+///     }
+/// }
+///
+/// let map = vec![(("Ada".to_string(), "Lovelace".to_string()), 1815)];
+/// assert_eq!(map.get(&Name("Ada", "Lovelace")), Some(&1815));
+/// ```
+pub trait Equivalent<K: ?Sized> {
+    /// Check whether `self` is equivalent to `key`.
+    fn equivalent(&self, key: &K) -> bool;
+}
+
+impl<Q, K> Equivalent<K> for Q
+where
+    Q: PartialEq + ?Sized,
+    K: Borrow<Q> + ?Sized,
+{
+    fn equivalent(&self, key: &K) -> bool {
+        self == key.borrow()
+    }
+}
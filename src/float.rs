@@ -0,0 +1,105 @@
+//! Floating-point keys that compare by total order.
+//!
+//! Since `NaN != NaN`, a map with floating-point keys grows a new pair every time an entry for
+//! `NaN` is looked up. [`TotalKey`] wraps a float so that it compares with [`f32::total_cmp`] or
+//! [`f64::total_cmp`] instead, making it a well-behaved key for [`AssocStrictExt`],
+//! [`AssocSortedExt`], and the std maps.
+//!
+//! All NaNs are treated as the same key, whatever their sign or payload. This matters because
+//! computed NaNs are not always [`f32::NAN`]: on x86, `0.0 / 0.0` produces a NaN with the sign bit
+//! set.
+//!
+//! ```rust
+//! use assoc::float::TotalKey;
+//! use assoc::AssocStrictExt;
+//!
+//! let mut map = vec![(TotalKey(1.0), "a")];
+//! map.entry(TotalKey(f32::NAN)).or_insert("b");
+//! map.entry(TotalKey(-f32::NAN)).or_insert("c");
+//! map.entry(TotalKey(f32::from_bits(0x7fc0_0001))).or_insert("d");
+//! assert_eq!(map, [(TotalKey(1.0), "a"), (TotalKey(f32::NAN), "b")]);
+//! ```
+//!
+//! [`AssocStrictExt`]: crate::AssocStrictExt
+//! [`AssocSortedExt`]: crate::AssocSortedExt
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+
+/// A floating-point key that compares by total order.
+///
+/// Every NaN is treated as the canonical NaN, [`f32::NAN`] or [`f64::NAN`], so all NaNs are equal
+/// to each other and hash the same. Other keys are equal exactly when their bit patterns are
+/// equal, so `0.0` and `-0.0` are different keys.
+/// Keys are ordered like `total_cmp`: the negative numbers, `-0.0`, `0.0`, the positive numbers,
+/// and NaN last.
+///
+/// Implemented for [`f32`] and [`f64`].
+///
+/// ```rust
+/// use assoc::float::TotalKey;
+///
+/// assert_eq!(TotalKey(f64::NAN), TotalKey(f64::NAN));
+/// assert_eq!(TotalKey(-f64::NAN), TotalKey(f64::NAN));
+/// assert!(TotalKey(-f64::NAN) > TotalKey(f64::INFINITY));
+/// assert_ne!(TotalKey(0.0_f64), TotalKey(-0.0));
+/// assert!(TotalKey(-0.0_f64) < TotalKey(0.0));
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TotalKey<F>(pub F);
+
+impl<F> TotalKey<F> {
+    /// Unwrap the float.
+    pub fn into_inner(self) -> F {
+        self.0
+    }
+}
+
+impl<F> From<F> for TotalKey<F> {
+    fn from(f: F) -> TotalKey<F> {
+        TotalKey(f)
+    }
+}
+
+macro_rules! impl_total_key {
+    ($f:ty) => {
+        impl TotalKey<$f> {
+            /// Get the float with every NaN replaced by the canonical one.
+            fn canonical(&self) -> $f {
+                if self.0.is_nan() {
+                    <$f>::NAN
+                } else {
+                    self.0
+                }
+            }
+        }
+
+        impl PartialEq for TotalKey<$f> {
+            fn eq(&self, other: &Self) -> bool {
+                self.canonical().to_bits() == other.canonical().to_bits()
+            }
+        }
+
+        impl Eq for TotalKey<$f> {}
+
+        impl PartialOrd for TotalKey<$f> {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for TotalKey<$f> {
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.canonical().total_cmp(&other.canonical())
+            }
+        }
+
+        impl Hash for TotalKey<$f> {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.canonical().to_bits().hash(state);
+            }
+        }
+    };
+}
+
+impl_total_key!(f32);
+impl_total_key!(f64);
@@ -21,6 +21,10 @@
 //! assert_eq!(map.get(&MyKey::A), Some(&2));
 //! ```
 //!
+//! [`AssocVec`] is an owned map type built on the same machinery.
+//! Unlike a bare `Vec<(K, V)>`, it guarantees that its keys are unique, so it can be used in
+//! struct fields and signatures that should encode "this is a map".
+//!
 //! ## Why?
 //!
 //! [`std::collections`] offers two maps: [`HashMap`] and [`BTreeMap`].
@@ -62,11 +66,23 @@
 //! For larger maps, the cost of linear searches begins to dominate.
 //! Unsurprisingly, `Vec` has a lower memory footprint than `HashMap` and `BTreeMap`.
 //!
+//! If the keys do implement [`Ord`], [`AssocSortedExt`] keeps the vector sorted by key and looks
+//! up keys with binary search in `O(log N)` comparisons instead.
+//!
 //! The `Entry` API eliminates the need for multiple sequential lookups (e.g. check for existence
 //! of a key, then add the key if it doesn't exist).
 //! Invoking [`AssocExt::entry`] requires one initial linear search, then constant time access for in-place
 //! mutation thereafter.
 //!
+//! ## Ordering
+//!
+//! Pairs are kept in the order they were inserted, and iteration follows that order.
+//! Removal methods come in two flavors: [`AssocExt::remove`] and [`AssocExt::remove_entry`] move
+//! the last pair into the removed pair's slot in constant time, while
+//! [`AssocExt::shift_remove`] and [`AssocExt::shift_remove_entry`] shift the following pairs
+//! down to preserve order.
+//! [`OccupiedEntry`][vec::OccupiedEntry] offers the same choice.
+//!
 //! ## `PartialEq` vs `Eq`
 //!
 //! Strictly speaking, a map's keys should implement `Eq`, which is why this crate provides a
@@ -88,10 +104,56 @@
 //!
 //! Using floating points as keys in a map is often a code smell, but for use cases that don't run
 //! into NaNs `AssocExt` is a great fit.
+//! When NaNs can occur, wrap the keys in [`TotalKey`][float::TotalKey], which compares floats by
+//! total order, treats all NaNs as one key, and implements `Eq`.
+//!
+//! ## `no_std`
+//!
+//! The crate only needs [`alloc`], so it can be used on targets without the standard library by
+//! disabling the default `std` feature:
+//!
+//! ```toml
+//! [dependencies]
+//! assoc = { version = "0.1", default-features = false }
+//! ```
+//!
+//! The `std` feature adds:
+//!
+//! - [`std::error::Error`] implementations for the error types,
+//! - conversions to and from [`HashMap`]: [`AssocExt::into_hash_map`], [`vec::from_hash_map`],
+//!   [`AssocVec::into_hash_map`], and `From<HashMap>` for [`AssocVec`],
+//! - the `std` features of `serde` and `arrayvec`, when those features are enabled.
+//!
+//! The `rayon`, `proptest`, and `quickcheck` features require the standard library, so they
+//! enable `std`.
+//!
+//! The `smallvec` and `arrayvec` features implement [`AssocExt`] for `SmallVec` and `ArrayVec`,
+//! so that small maps don't need a heap allocation at all. See [`Storage`][vec::Storage].
+//!
+//! The `rayon` feature adds parallel iterators over the keys and values of a map through
+//! `AssocParExt`.
+//!
+//! The `proptest` and `quickcheck` features add generators of maps without duplicate keys for
+//! property testing. See `testing`.
 //!
 //! [`HashMap`]: std::collections::HashMap
 //! [`BTreeMap`]: std::collections::BTreeMap
 //! [`Entry`]: vec::Entry
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub mod equivalent;
+pub mod float;
+pub mod map;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(any(feature = "proptest", feature = "quickcheck"))]
+pub mod testing;
 pub mod vec;
 
-pub use vec::{AssocExt, AssocStrictExt};
+pub use equivalent::Equivalent;
+pub use map::AssocVec;
+#[cfg(feature = "rayon")]
+pub use vec::AssocParExt;
+pub use vec::{AssocExt, AssocMultiExt, AssocSliceExt, AssocSortedExt, AssocStrictExt};
@@ -0,0 +1,885 @@
+//! An owned associative array that guarantees its keys are unique.
+//!
+//! [`AssocVec`] wraps a [`Vec<(K, V)>`][Vec] and only exposes operations that keep at most one
+//! pair per key, which makes it suitable for struct fields and function signatures that should
+//! encode "this is a map".
+use alloc::borrow::ToOwned;
+use alloc::collections::BTreeMap;
+use alloc::vec::{self, Vec};
+use core::fmt;
+#[cfg(feature = "std")]
+use core::hash::Hash;
+use core::iter::FromIterator;
+use core::ops::Index;
+
+use crate::vec::{
+    Diff, Difference, Drain, Entry, EntryRef, EvictPolicy, ExtractIf, Intersection, IntoKeys,
+    IntoValues, Iter, IterMut, Keys, OccupiedEntry, OccupiedError, SymmetricDifference, Union,
+    Values, ValuesMut,
+};
+use crate::{AssocExt, Equivalent};
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+/// An associative array backed by a [`Vec<(K, V)>`][Vec] whose keys are guaranteed to be unique.
+///
+/// ```rust
+/// use assoc::AssocVec;
+///
+/// let mut map = AssocVec::new();
+/// map.insert("a", 1);
+/// map.insert("b", 2);
+/// map.insert("a", 3);
+/// assert_eq!(map.len(), 2);
+/// assert_eq!(map["a"], 3);
+/// ```
+#[derive(Clone)]
+pub struct AssocVec<K, V> {
+    vec: Vec<(K, V)>,
+}
+
+impl<K, V> AssocVec<K, V> {
+    /// Create an empty `AssocVec`.
+    ///
+    /// ```rust
+    /// use assoc::AssocVec;
+    ///
+    /// let map: AssocVec<&str, i32> = AssocVec::new();
+    /// assert!(map.is_empty());
+    /// ```
+    pub fn new() -> AssocVec<K, V> {
+        AssocVec { vec: Vec::new() }
+    }
+
+    /// Create an empty `AssocVec` with space for at least `capacity` pairs.
+    ///
+    /// ```rust
+    /// use assoc::AssocVec;
+    ///
+    /// let map: AssocVec<&str, i32> = AssocVec::with_capacity(10);
+    /// assert!(map.capacity() >= 10);
+    /// ```
+    pub fn with_capacity(capacity: usize) -> AssocVec<K, V> {
+        AssocVec {
+            vec: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Get the number of pairs the map can hold without reallocating.
+    pub fn capacity(&self) -> usize {
+        self.vec.capacity()
+    }
+
+    /// Get the number of pairs in the map.
+    ///
+    /// ```rust
+    /// use assoc::AssocVec;
+    ///
+    /// let map: AssocVec<_, _> = vec![("a", 1), ("b", 2)].into_iter().collect();
+    /// assert_eq!(map.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.vec.len()
+    }
+
+    /// Check whether the map contains no pairs.
+    pub fn is_empty(&self) -> bool {
+        self.vec.is_empty()
+    }
+
+    /// Remove all pairs from the map.
+    ///
+    /// ```rust
+    /// use assoc::AssocVec;
+    ///
+    /// let mut map: AssocVec<_, _> = vec![("a", 1), ("b", 2)].into_iter().collect();
+    /// map.clear();
+    /// assert!(map.is_empty());
+    /// ```
+    pub fn clear(&mut self) {
+        self.vec.clear();
+    }
+
+    /// View the pairs of the map as a slice.
+    ///
+    /// ```rust
+    /// use assoc::AssocVec;
+    ///
+    /// let map: AssocVec<_, _> = vec![("a", 1), ("b", 2)].into_iter().collect();
+    /// assert_eq!(map.as_slice(), &[("a", 1), ("b", 2)]);
+    /// ```
+    pub fn as_slice(&self) -> &[(K, V)] {
+        &self.vec
+    }
+
+    /// Unwrap the map into its underlying vector.
+    ///
+    /// ```rust
+    /// use assoc::AssocVec;
+    ///
+    /// let map: AssocVec<_, _> = vec![("a", 1), ("a", 2)].into_iter().collect();
+    /// assert_eq!(map.into_vec(), [("a", 2)]);
+    /// ```
+    pub fn into_vec(self) -> Vec<(K, V)> {
+        self.vec
+    }
+}
+
+impl<K, V> AssocVec<K, V>
+where
+    K: PartialEq,
+{
+    /// See [`AssocExt::entry`].
+    ///
+    /// ```rust
+    /// use assoc::AssocVec;
+    ///
+    /// let mut count = AssocVec::new();
+    /// for x in vec!["a", "b", "c", "b"] {
+    ///     *count.entry(x).or_insert(0) += 1;
+    /// }
+    /// assert_eq!(count["b"], 2);
+    /// ```
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V> {
+        AssocExt::entry(&mut self.vec, key)
+    }
+
+    /// See [`AssocExt::entry_ref`].
+    ///
+    /// ```rust
+    /// use assoc::AssocVec;
+    ///
+    /// let mut count: AssocVec<String, u32> = AssocVec::new();
+    /// for word in "a b a".split(' ') {
+    ///     *count.entry_ref(word).or_insert(0) += 1;
+    /// }
+    /// assert_eq!(count["a"], 2);
+    /// ```
+    pub fn entry_ref<'a, 'b, Q>(&'a mut self, key: &'b Q) -> EntryRef<'a, 'b, K, Q, V>
+    where
+        Q: Equivalent<K> + ToOwned<Owned = K> + ?Sized,
+    {
+        AssocExt::entry_ref(&mut self.vec, key)
+    }
+
+    /// See [`AssocExt::get`].
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        AssocExt::get(&self.vec, key)
+    }
+
+    /// See [`AssocExt::get_mut`].
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        AssocExt::get_mut(&mut self.vec, key)
+    }
+
+    /// See [`AssocExt::get_key_value`].
+    pub fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        AssocExt::get_key_value(&self.vec, key)
+    }
+
+    /// See [`AssocExt::contains_key`].
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        AssocExt::contains_key(&self.vec, key)
+    }
+
+    /// See [`AssocExt::insert`].
+    ///
+    /// ```rust
+    /// use assoc::AssocVec;
+    ///
+    /// let mut map = AssocVec::new();
+    /// assert_eq!(map.insert("a", 1), None);
+    /// assert_eq!(map.insert("a", 2), Some(1));
+    /// assert_eq!(map.len(), 1);
+    /// ```
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        AssocExt::insert(&mut self.vec, key, value)
+    }
+
+    /// See [`AssocExt::try_insert`].
+    ///
+    /// ```rust
+    /// use assoc::AssocVec;
+    ///
+    /// let mut map = AssocVec::new();
+    /// assert_eq!(map.try_insert("a", 1).unwrap(), &1);
+    /// assert_eq!(map.try_insert("a", 2).unwrap_err().value, 2);
+    /// assert_eq!(map["a"], 1);
+    /// ```
+    pub fn try_insert(&mut self, key: K, value: V) -> Result<&mut V, OccupiedError<'_, K, V>> {
+        AssocExt::try_insert(&mut self.vec, key, value)
+    }
+
+    /// See [`AssocExt::insert_with`].
+    ///
+    /// ```rust
+    /// use assoc::AssocVec;
+    ///
+    /// let mut counts = AssocVec::new();
+    /// counts.insert_with("a", 1, |old, new| *old += new);
+    /// counts.insert_with("a", 2, |old, new| *old += new);
+    /// assert_eq!(counts["a"], 3);
+    /// ```
+    pub fn insert_with<F>(&mut self, key: K, value: V, resolve: F) -> &mut V
+    where
+        F: FnOnce(&mut V, V),
+    {
+        AssocExt::insert_with(&mut self.vec, key, value, resolve)
+    }
+
+    /// See [`AssocExt::merge`].
+    ///
+    /// ```rust
+    /// use assoc::AssocVec;
+    ///
+    /// let mut a: AssocVec<_, _> = vec![("x", 1), ("y", 2)].into_iter().collect();
+    /// let b: AssocVec<_, _> = vec![("y", 3), ("z", 4)].into_iter().collect();
+    /// a.merge(b, |old, new| *old += new);
+    /// assert_eq!(a.as_slice(), &[("x", 1), ("y", 5), ("z", 4)]);
+    /// ```
+    pub fn merge<I, F>(&mut self, other: I, resolve: F)
+    where
+        I: IntoIterator<Item = (K, V)>,
+        F: FnMut(&mut V, V),
+    {
+        AssocExt::merge(&mut self.vec, other, resolve)
+    }
+
+    /// See [`AssocExt::extend_replace`].
+    pub fn extend_replace<I>(&mut self, iter: I) -> usize
+    where
+        I: IntoIterator<Item = (K, V)>,
+    {
+        AssocExt::extend_replace(&mut self.vec, iter)
+    }
+
+    /// See [`AssocExt::extend_keep`].
+    pub fn extend_keep<I>(&mut self, iter: I) -> usize
+    where
+        I: IntoIterator<Item = (K, V)>,
+    {
+        AssocExt::extend_keep(&mut self.vec, iter)
+    }
+
+    /// See [`AssocExt::remove`].
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        AssocExt::remove(&mut self.vec, key)
+    }
+
+    /// See [`AssocExt::remove_entry`].
+    pub fn remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        AssocExt::remove_entry(&mut self.vec, key)
+    }
+
+    /// See [`AssocExt::shift_remove`].
+    ///
+    /// ```rust
+    /// use assoc::AssocVec;
+    ///
+    /// let mut map: AssocVec<_, _> = vec![("a", 1), ("b", 2), ("c", 3)].into_iter().collect();
+    /// assert_eq!(map.shift_remove("a"), Some(1));
+    /// assert_eq!(map.as_slice(), &[("b", 2), ("c", 3)]);
+    /// ```
+    pub fn shift_remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        AssocExt::shift_remove(&mut self.vec, key)
+    }
+
+    /// See [`AssocExt::shift_remove_entry`].
+    pub fn shift_remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        AssocExt::shift_remove_entry(&mut self.vec, key)
+    }
+
+    /// See [`AssocExt::retain`].
+    ///
+    /// ```rust
+    /// use assoc::AssocVec;
+    ///
+    /// let mut map: AssocVec<_, _> = vec![("a", 1), ("b", 2), ("c", 3)].into_iter().collect();
+    /// map.retain(|_, v| {
+    ///     *v *= 10;
+    ///     *v > 10
+    /// });
+    /// assert_eq!(map.as_slice(), &[("b", 20), ("c", 30)]);
+    /// ```
+    pub fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        AssocExt::retain(&mut self.vec, f)
+    }
+
+    /// See [`AssocExt::drain`].
+    ///
+    /// ```rust
+    /// use assoc::AssocVec;
+    ///
+    /// let mut map: AssocVec<_, _> = vec![("a", 1), ("b", 2)].into_iter().collect();
+    /// assert_eq!(map.drain().count(), 2);
+    /// assert!(map.is_empty());
+    /// ```
+    pub fn drain(&mut self) -> Drain<'_, K, V> {
+        AssocExt::drain(&mut self.vec)
+    }
+
+    /// See [`AssocExt::extract_if`].
+    ///
+    /// ```rust
+    /// use assoc::AssocVec;
+    ///
+    /// let mut map: AssocVec<_, _> = vec![("a", 1), ("b", 2), ("c", 3)].into_iter().collect();
+    /// let extracted: Vec<_> = map.extract_if(|k, _| *k != "b").collect();
+    /// assert_eq!(extracted, [("a", 1), ("c", 3)]);
+    /// assert_eq!(map.as_slice(), &[("b", 2)]);
+    /// ```
+    pub fn extract_if<F>(&mut self, pred: F) -> ExtractIf<'_, K, V, F>
+    where
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        AssocExt::extract_if(&mut self.vec, pred)
+    }
+
+    /// See [`AssocExt::get_by`].
+    ///
+    /// ```rust
+    /// use assoc::AssocVec;
+    ///
+    /// let map: AssocVec<_, _> = vec![("apple", 1), ("banana", 2)].into_iter().collect();
+    /// assert_eq!(map.get_by(|k| k.starts_with('b')), Some(&2));
+    /// ```
+    pub fn get_by<P>(&self, pred: P) -> Option<&V>
+    where
+        P: FnMut(&K) -> bool,
+    {
+        AssocExt::get_by(&self.vec, pred)
+    }
+
+    /// See [`AssocExt::get_mut_by`].
+    pub fn get_mut_by<P>(&mut self, pred: P) -> Option<&mut V>
+    where
+        P: FnMut(&K) -> bool,
+    {
+        AssocExt::get_mut_by(&mut self.vec, pred)
+    }
+
+    /// See [`AssocExt::first_key_value`].
+    ///
+    /// ```rust
+    /// use assoc::AssocVec;
+    ///
+    /// let map: AssocVec<_, _> = vec![("a", 1), ("b", 2)].into_iter().collect();
+    /// assert_eq!(map.first_key_value(), Some((&"a", &1)));
+    /// ```
+    pub fn first_key_value(&self) -> Option<(&K, &V)> {
+        AssocExt::first_key_value(&self.vec)
+    }
+
+    /// See [`AssocExt::last_key_value`].
+    pub fn last_key_value(&self) -> Option<(&K, &V)> {
+        AssocExt::last_key_value(&self.vec)
+    }
+
+    /// See [`AssocExt::pop_first`].
+    ///
+    /// ```rust
+    /// use assoc::AssocVec;
+    ///
+    /// let mut map: AssocVec<_, _> = vec![("a", 1), ("b", 2)].into_iter().collect();
+    /// assert_eq!(map.pop_first(), Some(("a", 1)));
+    /// assert_eq!(map.as_slice(), &[("b", 2)]);
+    /// ```
+    pub fn pop_first(&mut self) -> Option<(K, V)> {
+        AssocExt::pop_first(&mut self.vec)
+    }
+
+    /// See [`AssocExt::pop_last`].
+    pub fn pop_last(&mut self) -> Option<(K, V)> {
+        AssocExt::pop_last(&mut self.vec)
+    }
+
+    /// See [`AssocExt::first_entry`].
+    pub fn first_entry(&mut self) -> Option<OccupiedEntry<'_, K, V>> {
+        AssocExt::first_entry(&mut self.vec)
+    }
+
+    /// See [`AssocExt::last_entry`].
+    pub fn last_entry(&mut self) -> Option<OccupiedEntry<'_, K, V>> {
+        AssocExt::last_entry(&mut self.vec)
+    }
+
+    /// See [`AssocExt::get_index`].
+    ///
+    /// ```rust
+    /// use assoc::AssocVec;
+    ///
+    /// let map: AssocVec<_, _> = vec![("a", 1), ("b", 2)].into_iter().collect();
+    /// assert_eq!(map.get_index(1), Some((&"b", &2)));
+    /// ```
+    pub fn get_index(&self, index: usize) -> Option<(&K, &V)> {
+        AssocExt::get_index(&self.vec, index)
+    }
+
+    /// See [`AssocExt::get_index_mut`].
+    pub fn get_index_mut(&mut self, index: usize) -> Option<(&K, &mut V)> {
+        AssocExt::get_index_mut(&mut self.vec, index)
+    }
+
+    /// See [`AssocExt::index_of`].
+    pub fn index_of<Q>(&self, key: &Q) -> Option<usize>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        AssocExt::index_of(&self.vec, key)
+    }
+
+    /// See [`AssocExt::swap_indices`].
+    ///
+    /// ```rust
+    /// use assoc::AssocVec;
+    ///
+    /// let mut map: AssocVec<_, _> = vec![("a", 1), ("b", 2)].into_iter().collect();
+    /// map.swap_indices(0, 1);
+    /// assert_eq!(map.as_slice(), &[("b", 2), ("a", 1)]);
+    /// ```
+    pub fn swap_indices(&mut self, a: usize, b: usize) {
+        AssocExt::swap_indices(&mut self.vec, a, b)
+    }
+
+    /// See [`AssocExt::difference`].
+    pub fn difference<'a>(&'a self, other: &'a AssocVec<K, V>) -> Difference<'a, K, V> {
+        AssocExt::difference(&self.vec, &other.vec)
+    }
+
+    /// See [`AssocExt::intersection`].
+    pub fn intersection<'a>(&'a self, other: &'a AssocVec<K, V>) -> Intersection<'a, K, V> {
+        AssocExt::intersection(&self.vec, &other.vec)
+    }
+
+    /// See [`AssocExt::union`].
+    ///
+    /// ```rust
+    /// use assoc::AssocVec;
+    ///
+    /// let overrides: AssocVec<_, _> = vec![("timeout", 10)].into_iter().collect();
+    /// let defaults: AssocVec<_, _> = vec![("timeout", 30), ("retries", 5)].into_iter().collect();
+    /// let config: AssocVec<_, _> = overrides.union(&defaults).map(|(k, v)| (*k, *v)).collect();
+    /// assert_eq!(config["timeout"], 10);
+    /// assert_eq!(config["retries"], 5);
+    /// ```
+    pub fn union<'a>(&'a self, other: &'a AssocVec<K, V>) -> Union<'a, K, V> {
+        AssocExt::union(&self.vec, &other.vec)
+    }
+
+    /// See [`AssocExt::symmetric_difference`].
+    pub fn symmetric_difference<'a>(
+        &'a self,
+        other: &'a AssocVec<K, V>,
+    ) -> SymmetricDifference<'a, K, V> {
+        AssocExt::symmetric_difference(&self.vec, &other.vec)
+    }
+
+    /// See [`AssocExt::diff`].
+    pub fn diff<'a>(&'a self, other: &'a AssocVec<K, V>) -> Diff<'a, K, V> {
+        AssocExt::diff(&self.vec, &other.vec)
+    }
+
+    /// See [`AssocExt::get_disjoint_mut`].
+    pub fn get_disjoint_mut<Q, const N: usize>(&mut self, keys: [&Q; N]) -> [Option<&mut V>; N]
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        AssocExt::get_disjoint_mut(&mut self.vec, keys)
+    }
+
+    /// See [`AssocExt::get_promote`].
+    pub fn get_promote<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        AssocExt::get_promote(&mut self.vec, key)
+    }
+
+    /// See [`AssocExt::get_touch`].
+    pub fn get_touch<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        AssocExt::get_touch(&mut self.vec, key)
+    }
+
+    /// See [`AssocExt::insert_bounded`].
+    pub fn insert_bounded(
+        &mut self,
+        key: K,
+        value: V,
+        max_len: usize,
+        policy: EvictPolicy,
+    ) -> Option<(K, V)> {
+        AssocExt::insert_bounded(&mut self.vec, key, value, max_len, policy)
+    }
+
+    /// Get a cursor pointing at the first pair of the map, for editing the map in a single pass.
+    ///
+    /// Unlike [`AssocExt::cursor_mut`], the cursor rejects inserting a key that is already in the
+    /// map. See [`CursorMut`].
+    pub fn cursor_mut(&mut self) -> CursorMut<'_, K, V> {
+        CursorMut {
+            inner: AssocExt::cursor_mut(&mut self.vec),
+        }
+    }
+
+    /// See [`AssocExt::into_hash_map`].
+    ///
+    /// Requires the `std` feature.
+    #[cfg(feature = "std")]
+    pub fn into_hash_map(self) -> HashMap<K, V>
+    where
+        K: Hash + Eq,
+    {
+        AssocExt::into_hash_map(self.vec)
+    }
+
+    /// See [`AssocExt::into_btree_map`].
+    pub fn into_btree_map(self) -> BTreeMap<K, V>
+    where
+        K: Ord,
+    {
+        AssocExt::into_btree_map(self.vec)
+    }
+
+    /// See [`AssocExt::pairs`].
+    ///
+    /// ```rust
+    /// use assoc::AssocVec;
+    ///
+    /// let map: AssocVec<_, _> = vec![("a", 1), ("b", 2)].into_iter().collect();
+    /// let pairs: Vec<(&&str, &i32)> = map.iter().collect();
+    /// assert_eq!(pairs, [(&"a", &1), (&"b", &2)]);
+    /// ```
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        AssocExt::pairs(&self.vec)
+    }
+
+    /// See [`AssocExt::pairs_mut`].
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        AssocExt::pairs_mut(&mut self.vec)
+    }
+
+    /// See [`AssocExt::keys`].
+    /// Since keys are unique, this yields the same keys as [`AssocExt::unique_keys`].
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        AssocExt::keys(&self.vec)
+    }
+
+    /// See [`AssocExt::into_keys`].
+    pub fn into_keys(self) -> IntoKeys<K, V> {
+        AssocExt::into_keys(self.vec)
+    }
+
+    /// See [`AssocExt::values`].
+    pub fn values(&self) -> Values<'_, K, V> {
+        AssocExt::values(&self.vec)
+    }
+
+    /// See [`AssocExt::values_mut`].
+    pub fn values_mut(&mut self) -> ValuesMut<'_, K, V> {
+        AssocExt::values_mut(&mut self.vec)
+    }
+
+    /// See [`AssocExt::into_values`].
+    pub fn into_values(self) -> IntoValues<K, V> {
+        AssocExt::into_values(self.vec)
+    }
+}
+
+impl<K, V> Default for AssocVec<K, V> {
+    fn default() -> Self {
+        AssocVec::new()
+    }
+}
+
+impl<K: fmt::Debug, V: fmt::Debug> fmt::Debug for AssocVec<K, V> {
+    /// ```rust
+    /// use assoc::AssocVec;
+    ///
+    /// let map: AssocVec<_, _> = vec![("a", 1), ("b", 2)].into_iter().collect();
+    /// assert_eq!(format!("{:?}", map), r#"{"a": 1, "b": 2}"#);
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(self.vec.iter().map(|(k, v)| (k, v)))
+            .finish()
+    }
+}
+
+/// Two maps are equal if they contain the same pairs, regardless of order.
+///
+/// ```rust
+/// use assoc::AssocVec;
+///
+/// let a: AssocVec<_, _> = vec![("a", 1), ("b", 2)].into_iter().collect();
+/// let b: AssocVec<_, _> = vec![("b", 2), ("a", 1)].into_iter().collect();
+/// assert_eq!(a, b);
+/// ```
+impl<K, V> PartialEq for AssocVec<K, V>
+where
+    K: PartialEq,
+    V: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().all(|(k, v)| other.get(k) == Some(v))
+    }
+}
+
+impl<K: Eq, V: Eq> Eq for AssocVec<K, V> {}
+
+impl<K, V, Q> Index<&Q> for AssocVec<K, V>
+where
+    K: PartialEq,
+    Q: Equivalent<K> + ?Sized,
+{
+    type Output = V;
+
+    /// Get a reference to the value associated with a key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key is not present in the map.
+    ///
+    /// ```rust
+    /// use assoc::AssocVec;
+    ///
+    /// let map: AssocVec<_, _> = vec![("a", 1)].into_iter().collect();
+    /// assert_eq!(map[&"a"], 1);
+    /// ```
+    fn index(&self, key: &Q) -> &V {
+        self.get(key).expect("key not found in AssocVec")
+    }
+}
+
+/// Collect pairs into a map. If a key appears more than once, the last value wins and the pair
+/// keeps the position of the key's first occurrence.
+///
+/// ```rust
+/// use assoc::AssocVec;
+///
+/// let map: AssocVec<_, _> = vec![("a", 1), ("b", 2), ("a", 3)].into_iter().collect();
+/// assert_eq!(map.as_slice(), &[("a", 3), ("b", 2)]);
+/// ```
+impl<K: PartialEq, V> FromIterator<(K, V)> for AssocVec<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = AssocVec::new();
+        map.extend(iter);
+        map
+    }
+}
+
+/// Insert pairs into the map, overwriting the values of existing keys.
+///
+/// ```rust
+/// use assoc::AssocVec;
+///
+/// let mut map: AssocVec<_, _> = vec![("a", 1)].into_iter().collect();
+/// map.extend(vec![("a", 2), ("b", 3)]);
+/// assert_eq!(map.as_slice(), &[("a", 2), ("b", 3)]);
+/// ```
+impl<K: PartialEq, V> Extend<(K, V)> for AssocVec<K, V> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (k, v) in iter {
+            self.insert(k, v);
+        }
+    }
+}
+
+impl<K, V> IntoIterator for AssocVec<K, V> {
+    type Item = (K, V);
+    type IntoIter = vec::IntoIter<(K, V)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.vec.into_iter()
+    }
+}
+
+impl<'a, K: PartialEq, V> IntoIterator for &'a AssocVec<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, K: PartialEq, V> IntoIterator for &'a mut AssocVec<K, V> {
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<K, V> From<AssocVec<K, V>> for Vec<(K, V)> {
+    fn from(map: AssocVec<K, V>) -> Self {
+        map.vec
+    }
+}
+
+/// Requires the `std` feature.
+///
+/// ```rust
+/// use std::collections::HashMap;
+///
+/// use assoc::AssocVec;
+///
+/// let map: AssocVec<_, _> = HashMap::from([("a", 1)]).into();
+/// assert_eq!(map["a"], 1);
+/// ```
+#[cfg(feature = "std")]
+impl<K, V, S> From<HashMap<K, V, S>> for AssocVec<K, V> {
+    fn from(map: HashMap<K, V, S>) -> Self {
+        AssocVec {
+            vec: crate::vec::from_hash_map(map),
+        }
+    }
+}
+
+/// ```rust
+/// use std::collections::BTreeMap;
+///
+/// use assoc::AssocVec;
+///
+/// let map: AssocVec<_, _> = BTreeMap::from([("b", 2), ("a", 1)]).into();
+/// assert_eq!(map.into_vec(), [("a", 1), ("b", 2)]);
+/// ```
+impl<K, V> From<BTreeMap<K, V>> for AssocVec<K, V> {
+    fn from(map: BTreeMap<K, V>) -> Self {
+        AssocVec {
+            vec: crate::vec::from_btree_map(map),
+        }
+    }
+}
+
+/// A cursor over an [`AssocVec`] that can edit the map at its position.
+///
+/// It works like [`vec::CursorMut`][crate::vec::CursorMut], except that inserting a key that is
+/// already in the map fails and hands the pair back, so the keys stay unique.
+///
+/// Returned by [`AssocVec::cursor_mut`].
+///
+/// ```rust
+/// use assoc::AssocVec;
+///
+/// let mut map: AssocVec<_, _> = vec![("a", 1)].into_iter().collect();
+/// let mut cursor = map.cursor_mut();
+/// assert_eq!(cursor.insert_after("a", 2), Err(("a", 2)));
+/// assert_eq!(cursor.insert_after("b", 2), Ok(()));
+/// assert_eq!(map.len(), 2);
+/// assert_eq!(map.as_slice(), &[("a", 1), ("b", 2)]);
+/// ```
+#[derive(Debug)]
+pub struct CursorMut<'a, K: 'a, V: 'a> {
+    inner: crate::vec::CursorMut<'a, K, V>,
+}
+
+impl<'a, K: 'a, V: 'a> CursorMut<'a, K, V>
+where
+    K: PartialEq,
+{
+    /// See [`vec::CursorMut::index`][crate::vec::CursorMut::index].
+    pub fn index(&self) -> usize {
+        self.inner.index()
+    }
+
+    /// See [`vec::CursorMut::key`][crate::vec::CursorMut::key].
+    pub fn key(&self) -> Option<&K> {
+        self.inner.key()
+    }
+
+    /// See [`vec::CursorMut::value_mut`][crate::vec::CursorMut::value_mut].
+    pub fn value_mut(&mut self) -> Option<&mut V> {
+        self.inner.value_mut()
+    }
+
+    /// See [`vec::CursorMut::current`][crate::vec::CursorMut::current].
+    pub fn current(&mut self) -> Option<(&K, &mut V)> {
+        self.inner.current()
+    }
+
+    /// See [`vec::CursorMut::move_next`][crate::vec::CursorMut::move_next].
+    pub fn move_next(&mut self) {
+        self.inner.move_next()
+    }
+
+    /// See [`vec::CursorMut::move_prev`][crate::vec::CursorMut::move_prev].
+    pub fn move_prev(&mut self) {
+        self.inner.move_prev()
+    }
+
+    /// See [`vec::CursorMut::remove_current`][crate::vec::CursorMut::remove_current].
+    pub fn remove_current(&mut self) -> Option<(K, V)> {
+        self.inner.remove_current()
+    }
+
+    /// Insert a pair before the pair the cursor points at, or at the end if the cursor is past
+    /// the end. The cursor keeps pointing at the same pair.
+    ///
+    /// If the key is already in the map, the map is left unchanged and the pair is returned in
+    /// `Err`.
+    ///
+    /// ```rust
+    /// use assoc::AssocVec;
+    ///
+    /// let mut map: AssocVec<_, _> = vec![("b", 2)].into_iter().collect();
+    /// let mut cursor = map.cursor_mut();
+    /// assert_eq!(cursor.insert_before("b", 3), Err(("b", 3)));
+    /// assert_eq!(cursor.insert_before("a", 1), Ok(()));
+    /// assert_eq!(map.as_slice(), &[("a", 1), ("b", 2)]);
+    /// ```
+    pub fn insert_before(&mut self, key: K, value: V) -> Result<(), (K, V)> {
+        if self.contains_key(&key) {
+            return Err((key, value));
+        }
+        self.inner.insert_before(key, value);
+        Ok(())
+    }
+
+    /// Insert a pair after the pair the cursor points at, or at the end if the cursor is past the
+    /// end. The cursor keeps pointing at the same pair, so the inserted pair is visited next.
+    ///
+    /// If the key is already in the map, the map is left unchanged and the pair is returned in
+    /// `Err`.
+    pub fn insert_after(&mut self, key: K, value: V) -> Result<(), (K, V)> {
+        if self.contains_key(&key) {
+            return Err((key, value));
+        }
+        self.inner.insert_after(key, value);
+        Ok(())
+    }
+
+    fn contains_key(&self, key: &K) -> bool {
+        self.inner.as_slice().iter().any(|(k, _)| k == key)
+    }
+}
@@ -0,0 +1,265 @@
+//! Helpers for (de)serializing associative arrays as maps with [serde](https://serde.rs).
+//!
+//! By default a `Vec<(K, V)>` serializes as a sequence of pairs.
+//! The modules here are meant to be used with `#[serde(with = "...")]` so that the vector
+//! serializes as a map instead:
+//!
+//! ```rust
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Config {
+//!     #[serde(with = "assoc::serde::as_map")]
+//!     limits: Vec<(String, u32)>,
+//! }
+//!
+//! let config = Config {
+//!     limits: vec![("a".to_string(), 1), ("b".to_string(), 2)],
+//! };
+//! let json = serde_json::to_string(&config).unwrap();
+//! assert_eq!(json, r#"{"limits":{"a":1,"b":2}}"#);
+//! assert_eq!(serde_json::from_str::<Config>(&json).unwrap(), config);
+//! ```
+//!
+//! Serialized maps may contain the same key more than once.
+//! [`as_map`] keeps the last value for such keys, like deserializing into a
+//! [`HashMap`][std::collections::HashMap] does.
+//! Use [`as_map::first_wins`] to keep the first value instead, or [`as_map::strict`] to reject
+//! duplicate keys with an error.
+//!
+//! [`AssocVec`] always serializes as a map and deserializes with last-wins semantics.
+use alloc::vec::Vec;
+use core::fmt;
+use core::marker::PhantomData;
+
+use ::serde::de::{Deserialize, Deserializer, Error, MapAccess, Visitor};
+use ::serde::ser::{Serialize, Serializer};
+
+use crate::{AssocExt, AssocVec};
+
+/// Serialize a `Vec<(K, V)>` as a map and deserialize it from a map, keeping the last value of
+/// duplicate keys.
+///
+/// ```rust
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Config {
+///     #[serde(with = "assoc::serde::as_map")]
+///     limits: Vec<(String, u32)>,
+/// }
+///
+/// let config: Config = serde_json::from_str(r#"{"limits":{"a":1,"b":2,"a":3}}"#).unwrap();
+/// assert_eq!(config.limits, [("a".to_string(), 3), ("b".to_string(), 2)]);
+/// ```
+pub mod as_map {
+    use alloc::vec::Vec;
+
+    use ::serde::de::{Deserialize, Deserializer};
+    use ::serde::ser::{Serialize, Serializer};
+
+    use super::Duplicates;
+
+    /// Serialize the pairs as a map, in order.
+    pub fn serialize<K, V, S>(vec: &[(K, V)], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        K: Serialize,
+        V: Serialize,
+        S: Serializer,
+    {
+        super::serialize(vec, serializer)
+    }
+
+    /// Deserialize the pairs from a map, keeping the last value of duplicate keys.
+    pub fn deserialize<'de, K, V, D>(deserializer: D) -> Result<Vec<(K, V)>, D::Error>
+    where
+        K: Deserialize<'de> + PartialEq,
+        V: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        super::deserialize(deserializer, Duplicates::LastWins)
+    }
+
+    /// Like [`as_map`](super::as_map), but keeps the first value of duplicate keys.
+    ///
+    /// ```rust
+    /// use serde::Deserialize;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Config {
+    ///     #[serde(with = "assoc::serde::as_map::first_wins")]
+    ///     limits: Vec<(String, u32)>,
+    /// }
+    ///
+    /// let config: Config = serde_json::from_str(r#"{"limits":{"a":1,"b":2,"a":3}}"#).unwrap();
+    /// assert_eq!(config.limits, [("a".to_string(), 1), ("b".to_string(), 2)]);
+    /// ```
+    pub mod first_wins {
+        use alloc::vec::Vec;
+
+        use ::serde::de::{Deserialize, Deserializer};
+
+        use crate::serde::Duplicates;
+
+        pub use super::serialize;
+
+        /// Deserialize the pairs from a map, keeping the first value of duplicate keys.
+        pub fn deserialize<'de, K, V, D>(deserializer: D) -> Result<Vec<(K, V)>, D::Error>
+        where
+            K: Deserialize<'de> + PartialEq,
+            V: Deserialize<'de>,
+            D: Deserializer<'de>,
+        {
+            crate::serde::deserialize(deserializer, Duplicates::FirstWins)
+        }
+    }
+
+    /// Like [`as_map`](super::as_map), but fails to deserialize maps with duplicate keys.
+    /// The error gives the zero-based positions of the repeated entry and of the first entry with
+    /// the same key.
+    ///
+    /// ```rust
+    /// use serde::Deserialize;
+    ///
+    /// #[derive(Deserialize, Debug)]
+    /// struct Config {
+    ///     #[serde(with = "assoc::serde::as_map::strict")]
+    ///     limits: Vec<(String, u32)>,
+    /// }
+    ///
+    /// let err = serde_json::from_str::<Config>(r#"{"limits":{"a":1,"b":2,"a":3}}"#).unwrap_err();
+    /// assert!(err
+    ///     .to_string()
+    ///     .starts_with("duplicate map key at entry 2, first seen at entry 0"));
+    /// ```
+    pub mod strict {
+        use alloc::vec::Vec;
+
+        use ::serde::de::{Deserialize, Deserializer};
+
+        use crate::serde::Duplicates;
+
+        pub use super::serialize;
+
+        /// Deserialize the pairs from a map, failing if a key appears more than once.
+        pub fn deserialize<'de, K, V, D>(deserializer: D) -> Result<Vec<(K, V)>, D::Error>
+        where
+            K: Deserialize<'de> + PartialEq,
+            V: Deserialize<'de>,
+            D: Deserializer<'de>,
+        {
+            crate::serde::deserialize(deserializer, Duplicates::Error)
+        }
+    }
+}
+
+/// How to handle a key that appears more than once in a serialized map.
+#[derive(Clone, Copy)]
+enum Duplicates {
+    FirstWins,
+    LastWins,
+    Error,
+}
+
+fn serialize<K, V, S>(vec: &[(K, V)], serializer: S) -> Result<S::Ok, S::Error>
+where
+    K: Serialize,
+    V: Serialize,
+    S: Serializer,
+{
+    serializer.collect_map(vec.iter().map(|(k, v)| (k, v)))
+}
+
+fn deserialize<'de, K, V, D>(
+    deserializer: D,
+    duplicates: Duplicates,
+) -> Result<Vec<(K, V)>, D::Error>
+where
+    K: Deserialize<'de> + PartialEq,
+    V: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_map(MapVisitor {
+        duplicates,
+        marker: PhantomData,
+    })
+}
+
+struct MapVisitor<K, V> {
+    duplicates: Duplicates,
+    marker: PhantomData<fn() -> (K, V)>,
+}
+
+impl<'de, K, V> Visitor<'de> for MapVisitor<K, V>
+where
+    K: Deserialize<'de> + PartialEq,
+    V: Deserialize<'de>,
+{
+    type Value = Vec<(K, V)>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a map")
+    }
+
+    fn visit_map<A>(self, mut access: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        // Cap the preallocation so that a malicious size hint cannot exhaust memory.
+        let mut vec = Vec::with_capacity(access.size_hint().unwrap_or(0).min(4096));
+        while let Some((k, v)) = access.next_entry()? {
+            match self.duplicates {
+                Duplicates::FirstWins => {
+                    vec.entry(k).or_insert(v);
+                }
+                Duplicates::LastWins => {
+                    AssocExt::insert(&mut vec, k, v);
+                }
+                Duplicates::Error => {
+                    // The keys are not required to be printable, so report where they are.
+                    if let Some(first) = vec.index_of(&k) {
+                        return Err(A::Error::custom(format_args!(
+                            "duplicate map key at entry {}, first seen at entry {}",
+                            vec.len(),
+                            first,
+                        )));
+                    }
+                    vec.push((k, v));
+                }
+            }
+        }
+        Ok(vec)
+    }
+}
+
+impl<K, V> Serialize for AssocVec<K, V>
+where
+    K: Serialize,
+    V: Serialize,
+{
+    /// ```rust
+    /// use assoc::AssocVec;
+    ///
+    /// let map: AssocVec<_, _> = vec![("a", 1), ("b", 2)].into_iter().collect();
+    /// assert_eq!(serde_json::to_string(&map).unwrap(), r#"{"a":1,"b":2}"#);
+    /// ```
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize(self.as_slice(), serializer)
+    }
+}
+
+impl<'de, K, V> Deserialize<'de> for AssocVec<K, V>
+where
+    K: Deserialize<'de> + PartialEq,
+    V: Deserialize<'de>,
+{
+    /// ```rust
+    /// use assoc::AssocVec;
+    ///
+    /// let map: AssocVec<String, u32> = serde_json::from_str(r#"{"a":1,"a":2}"#).unwrap();
+    /// assert_eq!(map["a"], 2);
+    /// ```
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize(deserializer, Duplicates::LastWins).map(|vec| vec.into_iter().collect())
+    }
+}
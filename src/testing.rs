@@ -0,0 +1,88 @@
+//! Generators of associative arrays for property testing.
+//!
+//! The generated maps never contain duplicate keys, and shrinking keeps it that way, so code
+//! that expects a well-formed map can be tested without filtering its inputs.
+//!
+//! With the `proptest` feature, [`assoc_vec`] builds a strategy for `Vec<(K, V)>` maps. With the
+//! `quickcheck` feature, [`AssocVec`] implements `quickcheck::Arbitrary`.
+//!
+//! [`AssocVec`]: crate::AssocVec
+#[cfg(feature = "proptest")]
+use proptest::collection::SizeRange;
+#[cfg(feature = "proptest")]
+use proptest::strategy::Strategy;
+#[cfg(feature = "quickcheck")]
+use quickcheck::{Arbitrary, Gen};
+
+#[cfg(feature = "quickcheck")]
+use crate::AssocVec;
+
+/// Create a strategy that generates maps without duplicate keys, using `key` and `value` to
+/// generate the pairs.
+///
+/// The pairs are generated as a vector with a length in `size`, and the later pairs with a key
+/// that already occurred are dropped, so the map can be shorter than `size` allows. Shrinking
+/// shrinks the vector and drops duplicates again.
+///
+/// Requires the `proptest` feature.
+///
+/// ```rust
+/// use assoc::testing::assoc_vec;
+/// use assoc::AssocExt;
+/// use proptest::prelude::*;
+///
+/// proptest! {
+///     fn has_no_duplicate_keys(map in assoc_vec(0..10u8, any::<i32>(), 0..20)) {
+///         prop_assert!(!map.has_duplicate_keys());
+///     }
+/// }
+/// # has_no_duplicate_keys();
+/// ```
+#[cfg(feature = "proptest")]
+pub fn assoc_vec<K, V>(
+    key: K,
+    value: V,
+    size: impl Into<SizeRange>,
+) -> impl Strategy<Value = Vec<(K::Value, V::Value)>>
+where
+    K: Strategy,
+    K::Value: PartialEq,
+    V: Strategy,
+{
+    proptest::collection::vec((key, value), size).prop_map(crate::vec::from_iter_first_wins)
+}
+
+/// Requires the `quickcheck` feature.
+///
+/// Shrinking shrinks the underlying vector and drops the pairs whose shrunk keys collide.
+///
+/// ```rust
+/// use assoc::AssocVec;
+/// use quickcheck::{quickcheck, TestResult};
+///
+/// fn get_after_insert(mut map: AssocVec<u8, i32>, key: u8, value: i32) -> TestResult {
+///     map.insert(key, value);
+///     TestResult::from_bool(map.get(&key) == Some(&value))
+/// }
+///
+/// quickcheck(get_after_insert as fn(AssocVec<u8, i32>, u8, i32) -> TestResult);
+/// ```
+#[cfg(feature = "quickcheck")]
+impl<K, V> Arbitrary for AssocVec<K, V>
+where
+    K: Arbitrary + PartialEq,
+    V: Arbitrary,
+{
+    fn arbitrary(g: &mut Gen) -> AssocVec<K, V> {
+        Vec::<(K, V)>::arbitrary(g).into_iter().collect()
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = AssocVec<K, V>>> {
+        Box::new(
+            self.as_slice()
+                .to_vec()
+                .shrink()
+                .map(|vec| vec.into_iter().collect()),
+        )
+    }
+}
@@ -0,0 +1,170 @@
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use core::fmt;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+use crate::AssocExt;
+
+/// Build an associative array from an iterator, keeping the last value of duplicate keys.
+///
+/// Each key stays at the position of its first occurrence, like repeated calls to
+/// [`AssocExt::insert`].
+///
+/// ```rust
+/// use assoc::vec::from_iter_last_wins;
+///
+/// let map = from_iter_last_wins(vec![("a", 1), ("b", 2), ("a", 3)]);
+/// assert_eq!(map, [("a", 3), ("b", 2)]);
+/// ```
+pub fn from_iter_last_wins<K, V, I>(iter: I) -> Vec<(K, V)>
+where
+    K: PartialEq,
+    I: IntoIterator<Item = (K, V)>,
+{
+    let mut vec = Vec::new();
+    for (k, v) in iter {
+        AssocExt::insert(&mut vec, k, v);
+    }
+    vec
+}
+
+/// Build an associative array from an iterator, keeping the first value of duplicate keys.
+///
+/// ```rust
+/// use assoc::vec::from_iter_first_wins;
+///
+/// let map = from_iter_first_wins(vec![("a", 1), ("b", 2), ("a", 3)]);
+/// assert_eq!(map, [("a", 1), ("b", 2)]);
+/// ```
+pub fn from_iter_first_wins<K, V, I>(iter: I) -> Vec<(K, V)>
+where
+    K: PartialEq,
+    I: IntoIterator<Item = (K, V)>,
+{
+    let mut vec = Vec::new();
+    for (k, v) in iter {
+        vec.entry(k).or_insert(v);
+    }
+    vec
+}
+
+/// Build an associative array from an iterator, failing on the first duplicate key.
+///
+/// ```rust
+/// use assoc::vec::try_from_iter;
+///
+/// let map = try_from_iter(vec![("a", 1), ("b", 2)]).unwrap();
+/// assert_eq!(map, [("a", 1), ("b", 2)]);
+///
+/// let err = try_from_iter(vec![("a", 1), ("b", 2), ("a", 3)]).unwrap_err();
+/// assert_eq!((err.key, err.value), ("a", 3));
+/// ```
+pub fn try_from_iter<K, V, I>(iter: I) -> Result<Vec<(K, V)>, DuplicateKeyError<K, V>>
+where
+    K: PartialEq,
+    I: IntoIterator<Item = (K, V)>,
+{
+    let mut vec = Vec::new();
+    for (key, value) in iter {
+        if vec.contains_key(&key) {
+            return Err(DuplicateKeyError { key, value });
+        }
+        vec.push((key, value));
+    }
+    Ok(vec)
+}
+
+/// Group the values of an iterator by key, in order of each key's first occurrence.
+///
+/// The values of each key are kept in the order they appear in the iterator.
+/// Like the rest of the crate, this only requires the keys to implement [`PartialEq`].
+///
+/// ```rust
+/// use assoc::vec::group;
+///
+/// let words = ["apple", "bob", "avocado", "banana", "cherry"];
+/// let by_initial = group(words.iter().map(|w| (w.chars().next().unwrap(), *w)));
+/// assert_eq!(
+///     by_initial,
+///     [
+///         ('a', vec!["apple", "avocado"]),
+///         ('b', vec!["bob", "banana"]),
+///         ('c', vec!["cherry"]),
+///     ],
+/// );
+/// ```
+pub fn group<K, V, I>(iter: I) -> Vec<(K, Vec<V>)>
+where
+    K: PartialEq,
+    I: IntoIterator<Item = (K, V)>,
+{
+    let mut groups: Vec<(K, Vec<V>)> = Vec::new();
+    for (k, v) in iter {
+        groups.entry(k).or_insert_with(Vec::new).push(v);
+    }
+    groups
+}
+
+/// Build an associative array from a [`HashMap`], in the map's iteration order.
+///
+/// Requires the `std` feature.
+///
+/// ```rust
+/// use std::collections::HashMap;
+///
+/// use assoc::vec::from_hash_map;
+/// use assoc::AssocExt;
+///
+/// let mut map = HashMap::new();
+/// map.insert("a", 1);
+/// let vec = from_hash_map(map);
+/// assert_eq!(vec.get(&"a"), Some(&1));
+/// ```
+#[cfg(feature = "std")]
+pub fn from_hash_map<K, V, S>(map: HashMap<K, V, S>) -> Vec<(K, V)> {
+    map.into_iter().collect()
+}
+
+/// Build an associative array from a [`BTreeMap`], with the pairs sorted by key.
+///
+/// ```rust
+/// use std::collections::BTreeMap;
+///
+/// use assoc::vec::from_btree_map;
+///
+/// let mut map = BTreeMap::new();
+/// map.insert("b", 2);
+/// map.insert("a", 1);
+/// assert_eq!(from_btree_map(map), [("a", 1), ("b", 2)]);
+/// ```
+pub fn from_btree_map<K, V>(map: BTreeMap<K, V>) -> Vec<(K, V)> {
+    map.into_iter().collect()
+}
+
+/// The error returned by [`try_from_iter`] when a key occurs more than once.
+///
+/// It contains the second occurrence of the key and its value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicateKeyError<K, V> {
+    /// The duplicate key.
+    pub key: K,
+
+    /// The value that was paired with the duplicate key.
+    pub value: V,
+}
+
+impl<K: fmt::Debug, V> fmt::Display for DuplicateKeyError<K, V> {
+    /// ```rust
+    /// use assoc::vec::try_from_iter;
+    ///
+    /// let err = try_from_iter(vec![("a", 1), ("a", 2)]).unwrap_err();
+    /// assert_eq!(err.to_string(), r#"duplicate key "a""#);
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "duplicate key {:?}", self.key)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: fmt::Debug, V: fmt::Debug> std::error::Error for DuplicateKeyError<K, V> {}
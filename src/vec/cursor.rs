@@ -0,0 +1,170 @@
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use crate::vec::Storage;
+
+/// A cursor over an associative array that can edit the map at its position.
+///
+/// The cursor points at a pair, or past the end of the map. Removing and inserting pairs at the
+/// cursor does not search the map, so a single pass over the map can rewrite it in place.
+///
+/// Returned by [`AssocExt::cursor_mut`][crate::AssocExt::cursor_mut].
+///
+/// ```rust
+/// use assoc::AssocExt;
+///
+/// let mut headers = vec![("Host", "a.com"), ("X-Trace", "1"), ("Accept", "*/*")];
+/// let mut cursor = headers.cursor_mut();
+/// while let Some((key, value)) = cursor.current() {
+///     if key.starts_with("X-") {
+///         cursor.remove_current();
+///     } else {
+///         if *key == "Host" {
+///             *value = "b.com";
+///             cursor.insert_after("Connection", "close");
+///         }
+///         cursor.move_next();
+///     }
+/// }
+/// assert_eq!(
+///     headers,
+///     [("Host", "b.com"), ("Connection", "close"), ("Accept", "*/*")],
+/// );
+/// ```
+#[derive(Debug)]
+pub struct CursorMut<'a, K: 'a, V: 'a, S = Vec<(K, V)>> {
+    vec: &'a mut S,
+    index: usize,
+    marker: PhantomData<&'a mut (K, V)>,
+}
+
+impl<'a, K: 'a, V: 'a, S: Storage<(K, V)>> CursorMut<'a, K, V, S> {
+    pub(crate) fn new(vec: &'a mut S) -> CursorMut<'a, K, V, S> {
+        CursorMut {
+            vec,
+            index: 0,
+            marker: PhantomData,
+        }
+    }
+
+    /// Get the pairs of the map the cursor edits.
+    pub(crate) fn as_slice(&self) -> &[(K, V)] {
+        self.vec.as_slice()
+    }
+
+    /// Get the index of the pair the cursor points at. If the cursor is past the end, this is
+    /// the length of the map.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Get a reference to the key of the pair the cursor points at, or `None` if the cursor is
+    /// past the end.
+    pub fn key(&self) -> Option<&K> {
+        self.vec.as_slice().get(self.index).map(|(k, _)| k)
+    }
+
+    /// Get a mutable reference to the value of the pair the cursor points at, or `None` if the
+    /// cursor is past the end.
+    pub fn value_mut(&mut self) -> Option<&mut V> {
+        self.vec.as_mut_slice().get_mut(self.index).map(|(_, v)| v)
+    }
+
+    /// Get the pair the cursor points at, or `None` if the cursor is past the end.
+    pub fn current(&mut self) -> Option<(&K, &mut V)> {
+        self.vec
+            .as_mut_slice()
+            .get_mut(self.index)
+            .map(|(k, v)| (&*k, v))
+    }
+
+    /// Move the cursor to the next pair. Does nothing if the cursor is past the end.
+    pub fn move_next(&mut self) {
+        if self.index < self.vec.as_slice().len() {
+            self.index += 1;
+        }
+    }
+
+    /// Move the cursor to the previous pair. Does nothing if the cursor is at the first pair.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let mut map = vec![("a", 1), ("b", 2)];
+    /// let mut cursor = map.cursor_mut();
+    /// cursor.move_next();
+    /// cursor.move_next();
+    /// assert_eq!(cursor.key(), None);
+    /// cursor.move_prev();
+    /// assert_eq!(cursor.key(), Some(&"b"));
+    /// ```
+    pub fn move_prev(&mut self) {
+        self.index = self.index.saturating_sub(1);
+    }
+
+    /// Remove the pair the cursor points at and return it, or return `None` if the cursor is
+    /// past the end. The cursor moves to the pair that followed the removed one.
+    ///
+    /// The pairs after it are shifted, so the order of the map is preserved.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let mut map = vec![("a", 1), ("b", 2), ("c", 3)];
+    /// let mut cursor = map.cursor_mut();
+    /// assert_eq!(cursor.remove_current(), Some(("a", 1)));
+    /// assert_eq!(cursor.key(), Some(&"b"));
+    /// assert_eq!(map, [("b", 2), ("c", 3)]);
+    /// ```
+    pub fn remove_current(&mut self) -> Option<(K, V)> {
+        if self.index < self.vec.as_slice().len() {
+            Some(self.vec.remove(self.index))
+        } else {
+            None
+        }
+    }
+
+    /// Insert a pair before the pair the cursor points at, or at the end if the cursor is past
+    /// the end. The cursor keeps pointing at the same pair.
+    ///
+    /// This does not check whether the key is already in the map.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let mut map = vec![("b", 2)];
+    /// let mut cursor = map.cursor_mut();
+    /// cursor.insert_before("a", 1);
+    /// assert_eq!(cursor.key(), Some(&"b"));
+    /// assert_eq!(map, [("a", 1), ("b", 2)]);
+    /// ```
+    pub fn insert_before(&mut self, key: K, value: V) {
+        self.vec.insert(self.index, (key, value));
+        self.index += 1;
+    }
+
+    /// Insert a pair after the pair the cursor points at, or at the end if the cursor is past the
+    /// end. The cursor keeps pointing at the same pair, so the inserted pair is visited next.
+    ///
+    /// This does not check whether the key is already in the map.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let mut map = vec![("a", 1)];
+    /// let mut cursor = map.cursor_mut();
+    /// cursor.insert_after("b", 2);
+    /// cursor.move_next();
+    /// assert_eq!(cursor.key(), Some(&"b"));
+    /// assert_eq!(map, [("a", 1), ("b", 2)]);
+    /// ```
+    pub fn insert_after(&mut self, key: K, value: V) {
+        let len = self.vec.as_slice().len();
+        if self.index < len {
+            self.vec.insert(self.index + 1, (key, value));
+        } else {
+            self.vec.insert(len, (key, value));
+            self.index += 1;
+        }
+    }
+}
@@ -1,29 +1,59 @@
+use alloc::borrow::ToOwned;
+use alloc::vec::Vec;
+use core::fmt;
+use core::marker::PhantomData;
+
+use crate::vec::Storage;
+
 /// A view into a single entry in an associative array. The entry may be vacant or occupied.
 ///
-/// Returned by the [`AssocExt::entry`][crate::AssocExt::entry] method.
+/// Returned by the [`AssocExt::entry`][crate::AssocExt::entry] and
+/// [`AssocSortedExt::entry`][crate::AssocSortedExt::entry] methods.
 #[derive(Debug)]
-pub enum Entry<'a, K, V>
+pub enum Entry<'a, K, V, S = Vec<(K, V)>>
 where
     K: 'a,
     V: 'a,
 {
     /// A vacant entry.
-    Vacant(VacantEntry<'a, K, V>),
+    Vacant(VacantEntry<'a, K, V, S>),
 
     /// An occupied entry.
-    Occupied(OccupiedEntry<'a, K, V>),
+    Occupied(OccupiedEntry<'a, K, V, S>),
 }
 
 /// A view into a vacant entry in an associative array. It is part of the [`Entry`] enum.
 #[derive(Debug)]
-pub struct VacantEntry<'a, K: 'a, V: 'a> {
-    vec: &'a mut Vec<(K, V)>,
+pub struct VacantEntry<'a, K: 'a, V: 'a, S = Vec<(K, V)>> {
+    vec: &'a mut S,
     key: K,
+    index: usize,
+    preserve_order: bool,
+    marker: PhantomData<&'a mut (K, V)>,
 }
 
-impl<'a, K: 'a, V: 'a> VacantEntry<'a, K, V> {
-    pub(crate) fn new(vec: &'a mut Vec<(K, V)>, key: K) -> VacantEntry<'a, K, V> {
-        VacantEntry { vec, key }
+impl<'a, K: 'a, V: 'a, S: Storage<(K, V)>> VacantEntry<'a, K, V, S> {
+    pub(crate) fn new(vec: &'a mut S, key: K) -> VacantEntry<'a, K, V, S> {
+        let index = vec.as_slice().len();
+        VacantEntry::new_at(vec, key, index)
+    }
+
+    /// Create a vacant entry that inserts its pair at `index` instead of at the end.
+    pub(crate) fn new_at(vec: &'a mut S, key: K, index: usize) -> VacantEntry<'a, K, V, S> {
+        VacantEntry {
+            vec,
+            key,
+            index,
+            preserve_order: false,
+            marker: PhantomData,
+        }
+    }
+
+    /// Make the entry returned by [`VacantEntry::insert_entry`] preserve order on removal, as
+    /// required by [`AssocSortedExt`][crate::AssocSortedExt].
+    pub(crate) fn preserving_order(mut self) -> VacantEntry<'a, K, V, S> {
+        self.preserve_order = true;
+        self
     }
 
     /// Get a reference to the key that would be used when inserting a value through a
@@ -39,68 +69,262 @@ impl<'a, K: 'a, V: 'a> VacantEntry<'a, K, V> {
 
     /// Set the value of the entry, and return a mutable reference to it.
     pub fn insert(self, v: V) -> &'a mut V {
-        self.vec.push((self.key, v));
-        let (_, v) = self.vec.last_mut().unwrap();
-        v
+        self.insert_entry(v).into_mut()
+    }
+
+    /// Set the value of the entry, and return an [`OccupiedEntry`] for the inserted pair.
+    ///
+    /// ```rust
+    /// use assoc::vec::Entry;
+    /// use assoc::AssocExt;
+    ///
+    /// let mut map = vec![("a", 1)];
+    /// if let Entry::Vacant(entry) = map.entry("b") {
+    ///     let entry = entry.insert_entry(2);
+    ///     assert_eq!(entry.key(), &"b");
+    ///     assert_eq!(entry.remove(), 2);
+    /// }
+    /// assert_eq!(map, [("a", 1)]);
+    /// ```
+    pub fn insert_entry(self, v: V) -> OccupiedEntry<'a, K, V, S> {
+        self.vec.insert(self.index, (self.key, v));
+        let entry = OccupiedEntry::new(self.vec, self.index);
+        if self.preserve_order {
+            entry.preserving_order()
+        } else {
+            entry
+        }
     }
 }
 
 /// A view into an occupied entry in an associative array. It is part of the [`Entry`] enum.
 #[derive(Debug)]
-pub struct OccupiedEntry<'a, K, V> {
-    vec: &'a mut Vec<(K, V)>,
-    key: K,
+pub struct OccupiedEntry<'a, K, V, S = Vec<(K, V)>> {
+    vec: &'a mut S,
     index: usize,
+    preserve_order: bool,
+    marker: PhantomData<&'a mut (K, V)>,
 }
 
-impl<'a, K: 'a, V: 'a> OccupiedEntry<'a, K, V> {
-    pub(crate) fn new(vec: &'a mut Vec<(K, V)>, key: K, index: usize) -> OccupiedEntry<'a, K, V> {
-        OccupiedEntry { vec, key, index }
+impl<'a, K: 'a, V: 'a, S: Storage<(K, V)>> OccupiedEntry<'a, K, V, S> {
+    pub(crate) fn new(vec: &'a mut S, index: usize) -> OccupiedEntry<'a, K, V, S> {
+        OccupiedEntry {
+            vec,
+            index,
+            preserve_order: false,
+            marker: PhantomData,
+        }
+    }
+
+    /// Make [`OccupiedEntry::remove`] and [`OccupiedEntry::remove_entry`] preserve order, as
+    /// required by [`AssocSortedExt`][crate::AssocSortedExt].
+    pub(crate) fn preserving_order(mut self) -> OccupiedEntry<'a, K, V, S> {
+        self.preserve_order = true;
+        self
+    }
+
+    /// Get the position of the entry's pair in the vector.
+    ///
+    /// ```rust
+    /// use assoc::vec::Entry;
+    /// use assoc::AssocExt;
+    ///
+    /// let mut map = vec![("a", 1), ("b", 2)];
+    /// if let Entry::Occupied(entry) = map.entry("b") {
+    ///     assert_eq!(entry.index(), 1);
+    /// }
+    /// ```
+    pub fn index(&self) -> usize {
+        self.index
     }
 
-    /// Get a reference to the key in the entry.
+    /// Get a reference to the key stored in the associative array.
     pub fn key(&self) -> &K {
-        &self.key
+        let (k, _) = &self.vec.as_slice()[self.index];
+        k
     }
 
     /// Take ownership of the key-value pair from the associative array.
+    ///
+    /// The last pair in the vector is moved into the removed pair's position, so this does not
+    /// preserve order. Use [`OccupiedEntry::shift_remove_entry`] to preserve order.
+    /// Entries obtained through [`AssocSortedExt`][crate::AssocSortedExt] always preserve order
+    /// so that the vector stays sorted.
     pub fn remove_entry(self) -> (K, V) {
+        if self.preserve_order {
+            return self.shift_remove_entry();
+        }
         self.vec.swap_remove(self.index)
     }
 
+    /// Take ownership of the key-value pair from the associative array, shifting all following
+    /// pairs down by one to preserve order.
+    ///
+    /// ```rust
+    /// use assoc::vec::Entry;
+    /// use assoc::AssocExt;
+    ///
+    /// let mut map = vec![("a", 1), ("b", 2), ("c", 3)];
+    /// if let Entry::Occupied(entry) = map.entry("a") {
+    ///     assert_eq!(entry.shift_remove_entry(), ("a", 1));
+    /// }
+    /// assert_eq!(map, [("b", 2), ("c", 3)]);
+    /// ```
+    pub fn shift_remove_entry(self) -> (K, V) {
+        self.vec.remove(self.index)
+    }
+
     /// Get a reference to the value in the entry.
     pub fn get(&self) -> &V {
-        let (_, v) = &self.vec[self.index];
+        let (_, v) = &self.vec.as_slice()[self.index];
         v
     }
 
     /// Get a mutable reference to the value in the entry.
     pub fn get_mut(&mut self) -> &mut V {
-        let (_, v) = &mut self.vec[self.index];
+        let (_, v) = &mut self.vec.as_mut_slice()[self.index];
         v
     }
 
     /// Convert the entry into a mutable reference to the value in the entry.
     /// This mutable reference has a lifetime bound by the lifetime of the associative array.
     pub fn into_mut(self) -> &'a mut V {
-        let (_, v) = &mut self.vec[self.index];
+        let (_, v) = &mut self.vec.as_mut_slice()[self.index];
         v
     }
 
     /// Set the value of the entry and return the entry's old value.
     pub fn insert(&mut self, mut v: V) -> V {
-        std::mem::swap(&mut v, &mut self.vec[self.index].1);
+        core::mem::swap(&mut v, &mut self.vec.as_mut_slice()[self.index].1);
         v
     }
 
     /// Take the value out of the entry.
+    ///
+    /// Like [`OccupiedEntry::remove_entry`], this does not preserve order.
     pub fn remove(self) -> V {
         let (_, v) = self.remove_entry();
         v
     }
+
+    /// Take the value out of the entry, preserving the order of the remaining pairs.
+    ///
+    /// ```rust
+    /// use assoc::vec::Entry;
+    /// use assoc::AssocExt;
+    ///
+    /// let mut map = vec![("a", 1), ("b", 2), ("c", 3)];
+    /// if let Entry::Occupied(entry) = map.entry("a") {
+    ///     assert_eq!(entry.shift_remove(), 1);
+    /// }
+    /// assert_eq!(map, [("b", 2), ("c", 3)]);
+    /// ```
+    pub fn shift_remove(self) -> V {
+        let (_, v) = self.shift_remove_entry();
+        v
+    }
+
+    /// Replace the value of the entry with the result of `f`, which is given the key and the old
+    /// value. If `f` returns `None`, the pair is removed and a vacant entry for the key is
+    /// returned instead.
+    ///
+    /// Like [`OccupiedEntry::remove`], removing the pair does not preserve order, except for
+    /// entries obtained through [`AssocSortedExt`][crate::AssocSortedExt].
+    /// If `f` returns a value, the pair stays where it was.
+    ///
+    /// ```rust
+    /// use assoc::vec::Entry;
+    /// use assoc::AssocExt;
+    ///
+    /// let mut map = vec![("a", 1), ("b", 2), ("c", 3)];
+    /// if let Entry::Occupied(entry) = map.entry("a") {
+    ///     let entry = entry.replace_entry_with(|_, v| Some(v + 10));
+    ///     assert!(matches!(entry, Entry::Occupied(_)));
+    /// }
+    /// assert_eq!(map, [("a", 11), ("b", 2), ("c", 3)]);
+    ///
+    /// if let Entry::Occupied(entry) = map.entry("a") {
+    ///     let entry = entry.replace_entry_with(|_, _| None);
+    ///     assert!(matches!(entry, Entry::Vacant(_)));
+    /// }
+    /// assert_eq!(map, [("c", 3), ("b", 2)]);
+    /// ```
+    pub fn replace_entry_with<F>(self, f: F) -> Entry<'a, K, V, S>
+    where
+        F: FnOnce(&K, V) -> Option<V>,
+    {
+        let index = self.index;
+        // Take the pair out with an O(1) swap when order doesn't matter, and put it back in the
+        // same place if `f` keeps it.
+        let (k, v) = if self.preserve_order {
+            self.vec.remove(index)
+        } else {
+            self.vec.swap_remove(index)
+        };
+        match f(&k, v) {
+            Some(v) => {
+                if self.preserve_order {
+                    self.vec.insert(index, (k, v));
+                } else {
+                    let last = self.vec.as_slice().len();
+                    self.vec.insert(last, (k, v));
+                    self.vec.as_mut_slice().swap(index, last);
+                }
+                Entry::Occupied(self)
+            }
+            None if self.preserve_order => {
+                Entry::Vacant(VacantEntry::new_at(self.vec, k, index).preserving_order())
+            }
+            None => Entry::Vacant(VacantEntry::new(self.vec, k)),
+        }
+    }
 }
 
-impl<'a, K, V> Entry<'a, K, V> {
+/// The error returned by [`AssocExt::try_insert`][crate::AssocExt::try_insert] when the key is
+/// already present.
+///
+/// It contains the occupied entry and the value that was not inserted.
+#[derive(Debug)]
+pub struct OccupiedError<'a, K: 'a, V: 'a, S = Vec<(K, V)>> {
+    /// The entry in the associative array that was already occupied.
+    pub entry: OccupiedEntry<'a, K, V, S>,
+
+    /// The value which was not inserted, because the entry was already occupied.
+    pub value: V,
+}
+
+impl<K: fmt::Debug, V: fmt::Debug, S: Storage<(K, V)>> fmt::Display for OccupiedError<'_, K, V, S> {
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let mut map = vec![("a", 1)];
+    /// let err = map.try_insert("a", 2).unwrap_err();
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     r#"failed to insert 2, key "a" already exists with value 1"#,
+    /// );
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "failed to insert {:?}, key {:?} already exists with value {:?}",
+            self.value,
+            self.entry.key(),
+            self.entry.get(),
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, V, S> std::error::Error for OccupiedError<'_, K, V, S>
+where
+    K: fmt::Debug,
+    V: fmt::Debug,
+    S: Storage<(K, V)> + fmt::Debug,
+{
+}
+
+impl<'a, K, V, S: Storage<(K, V)>> Entry<'a, K, V, S> {
     /// Ensures a value is in the entry by inserting the default if it is empty, and returns a
     /// mutable reference to the value in the entry.
     ///
@@ -166,6 +390,77 @@ impl<'a, K, V> Entry<'a, K, V> {
         }
     }
 
+    /// Ensures a value is in the entry by inserting the result of the fallible default function if
+    /// empty, and returns a mutable reference to the value in the entry.
+    /// If the default function fails, the error is returned and the map is left unchanged.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let mut map = vec![("a", 1)];
+    /// assert_eq!(map.entry("a").or_try_insert_with(|| "x".parse::<i32>()), Ok(&mut 1));
+    /// assert!(map.entry("b").or_try_insert_with(|| "x".parse::<i32>()).is_err());
+    /// assert_eq!(map.entry("b").or_try_insert_with(|| "2".parse::<i32>()), Ok(&mut 2));
+    /// assert_eq!(map, [("a", 1), ("b", 2)]);
+    /// ```
+    pub fn or_try_insert_with<F, E>(self, default: F) -> Result<&'a mut V, E>
+    where
+        F: FnOnce() -> Result<V, E>,
+    {
+        match self {
+            Entry::Vacant(entry) => Ok(entry.insert(default()?)),
+            Entry::Occupied(entry) => Ok(entry.into_mut()),
+        }
+    }
+
+    /// Like [`Entry::or_try_insert_with`], but the default function is given a reference to the
+    /// key.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let mut map: Vec<(&str, i32)> = Vec::new();
+    /// assert_eq!(map.entry("12").or_try_insert_with_key(|key| key.parse()), Ok(&mut 12));
+    /// assert!(map.entry("ab").or_try_insert_with_key(|key| key.parse()).is_err());
+    /// assert_eq!(map, [("12", 12)]);
+    /// ```
+    pub fn or_try_insert_with_key<F, E>(self, default: F) -> Result<&'a mut V, E>
+    where
+        F: FnOnce(&K) -> Result<V, E>,
+    {
+        match self {
+            Entry::Vacant(entry) => {
+                let v = default(entry.key())?;
+                Ok(entry.insert(v))
+            }
+            Entry::Occupied(entry) => Ok(entry.into_mut()),
+        }
+    }
+
+    /// Set the value of the entry and return an [`OccupiedEntry`] for it, replacing the old value
+    /// if the entry was occupied.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let mut map = vec![("a", 1)];
+    /// let entry = map.entry("a").insert_entry(2);
+    /// assert_eq!(entry.get(), &2);
+    ///
+    /// let entry = map.entry("b").insert_entry(3);
+    /// assert_eq!(entry.key(), &"b");
+    /// assert_eq!(map, [("a", 2), ("b", 3)]);
+    /// ```
+    pub fn insert_entry(self, value: V) -> OccupiedEntry<'a, K, V, S> {
+        match self {
+            Entry::Vacant(entry) => entry.insert_entry(value),
+            Entry::Occupied(mut entry) => {
+                entry.insert(value);
+                entry
+            }
+        }
+    }
+
     /// Returns a reference to this entry's key.
     ///
     /// ```rust
@@ -194,7 +489,7 @@ impl<'a, K, V> Entry<'a, K, V> {
     /// map.entry("c").and_modify(|e| *e += 1).or_insert(9);
     /// assert_eq!(map.get(&"c"), Some(&4));
     /// ```
-    pub fn and_modify<F>(self, f: F) -> Entry<'a, K, V>
+    pub fn and_modify<F>(self, f: F) -> Entry<'a, K, V, S>
     where
         F: FnOnce(&mut V),
     {
@@ -206,12 +501,36 @@ impl<'a, K, V> Entry<'a, K, V> {
             }
         }
     }
+
+    /// Provides shared access to the key and owned access to the value of an occupied entry, and
+    /// allows replacing or removing it with [`OccupiedEntry::replace_entry_with`].
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let mut map = vec![("a", 1), ("b", 2)];
+    /// map.entry("a")
+    ///     .and_replace_entry_with(|_, v| if v > 1 { Some(v - 1) } else { None });
+    /// map.entry("b")
+    ///     .and_replace_entry_with(|_, v| if v > 1 { Some(v - 1) } else { None });
+    /// assert_eq!(map, [("b", 1)]);
+    /// ```
+    pub fn and_replace_entry_with<F>(self, f: F) -> Entry<'a, K, V, S>
+    where
+        F: FnOnce(&K, V) -> Option<V>,
+    {
+        match self {
+            Entry::Vacant(entry) => Entry::Vacant(entry),
+            Entry::Occupied(entry) => entry.replace_entry_with(f),
+        }
+    }
 }
 
-impl<'a, K, V> Entry<'a, K, V>
+impl<'a, K, V, S> Entry<'a, K, V, S>
 where
     K: 'a,
     V: 'a + Default,
+    S: Storage<(K, V)>,
 {
     /// ```rust
     /// use assoc::AssocExt;
@@ -224,3 +543,180 @@ where
         self.or_insert_with(Default::default)
     }
 }
+
+/// A view into a single entry in an associative array, looked up by a borrowed key. The entry may
+/// be vacant or occupied.
+///
+/// Unlike [`Entry`], the key is only converted into an owned key if a value is inserted into a
+/// vacant entry.
+///
+/// Returned by the [`AssocExt::entry_ref`][crate::AssocExt::entry_ref] method.
+#[derive(Debug)]
+pub enum EntryRef<'a, 'b, K, Q, V, S = Vec<(K, V)>>
+where
+    K: 'a,
+    V: 'a,
+    Q: ?Sized,
+{
+    /// A vacant entry.
+    Vacant(VacantEntryRef<'a, 'b, K, Q, V, S>),
+
+    /// An occupied entry.
+    Occupied(OccupiedEntry<'a, K, V, S>),
+}
+
+/// A view into a vacant entry in an associative array, looked up by a borrowed key. It is part of
+/// the [`EntryRef`] enum.
+#[derive(Debug)]
+pub struct VacantEntryRef<'a, 'b, K: 'a, Q: ?Sized, V: 'a, S = Vec<(K, V)>> {
+    vec: &'a mut S,
+    key: &'b Q,
+    marker: PhantomData<&'a mut (K, V)>,
+}
+
+impl<'a, 'b, K: 'a, Q: ?Sized, V: 'a, S> VacantEntryRef<'a, 'b, K, Q, V, S> {
+    pub(crate) fn new(vec: &'a mut S, key: &'b Q) -> VacantEntryRef<'a, 'b, K, Q, V, S> {
+        VacantEntryRef {
+            vec,
+            key,
+            marker: PhantomData,
+        }
+    }
+
+    /// Get a reference to the borrowed key that would be converted into an owned key when
+    /// inserting a value.
+    pub fn key(&self) -> &'b Q {
+        self.key
+    }
+}
+
+impl<'a, 'b, K, Q, V, S> VacantEntryRef<'a, 'b, K, Q, V, S>
+where
+    K: 'a,
+    V: 'a,
+    Q: ToOwned<Owned = K> + ?Sized,
+    S: Storage<(K, V)>,
+{
+    /// Convert the key into an owned key, set the value of the entry, and return a mutable
+    /// reference to the value.
+    pub fn insert(self, v: V) -> &'a mut V {
+        self.insert_entry(v).into_mut()
+    }
+
+    /// Convert the key into an owned key, set the value of the entry, and return an
+    /// [`OccupiedEntry`] for the inserted pair.
+    pub fn insert_entry(self, v: V) -> OccupiedEntry<'a, K, V, S> {
+        VacantEntry::new(self.vec, self.key.to_owned()).insert_entry(v)
+    }
+}
+
+impl<'a, 'b, K, Q, V, S> EntryRef<'a, 'b, K, Q, V, S>
+where
+    Q: ToOwned<Owned = K> + ?Sized,
+    S: Storage<(K, V)>,
+{
+    /// Ensures a value is in the entry by inserting the default if it is empty, and returns a
+    /// mutable reference to the value in the entry.
+    /// The key is only converted into an owned key if the entry is vacant.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let mut map = vec![("a".to_string(), 1)];
+    /// *map.entry_ref("a").or_insert(0) += 1;
+    /// *map.entry_ref("b").or_insert(0) += 1;
+    /// assert_eq!(map, [("a".to_string(), 2), ("b".to_string(), 1)]);
+    /// ```
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        match self {
+            EntryRef::Vacant(entry) => entry.insert(default),
+            EntryRef::Occupied(entry) => entry.into_mut(),
+        }
+    }
+
+    /// Ensures a value is in the entry by inserting the result of the default function if empty,
+    /// and returns a mutable reference to the value in the entry.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let mut map: Vec<(String, Vec<u32>)> = Vec::new();
+    /// map.entry_ref("a").or_insert_with(Vec::new).push(1);
+    /// map.entry_ref("a").or_insert_with(Vec::new).push(2);
+    /// assert_eq!(map, [("a".to_string(), vec![1, 2])]);
+    /// ```
+    pub fn or_insert_with<F>(self, default: F) -> &'a mut V
+    where
+        F: FnOnce() -> V,
+    {
+        match self {
+            EntryRef::Vacant(entry) => entry.insert(default()),
+            EntryRef::Occupied(entry) => entry.into_mut(),
+        }
+    }
+
+    /// Ensures a value is in the entry by inserting, if empty, the result of the default function,
+    /// which is given a reference to the borrowed key.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let mut map: Vec<(String, usize)> = Vec::new();
+    /// map.entry_ref("abc").or_insert_with_key(|key| key.len());
+    /// assert_eq!(map, [("abc".to_string(), 3)]);
+    /// ```
+    pub fn or_insert_with_key<F>(self, default: F) -> &'a mut V
+    where
+        F: FnOnce(&Q) -> V,
+    {
+        match self {
+            EntryRef::Vacant(entry) => {
+                let v = default(entry.key());
+                entry.insert(v)
+            }
+            EntryRef::Occupied(entry) => entry.into_mut(),
+        }
+    }
+
+    /// Provides in-place mutable access to an occupied entry before any potential inserts into the
+    /// associative array.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let mut map = vec![("a".to_string(), 1)];
+    /// map.entry_ref("a").and_modify(|e| *e += 1).or_insert(9);
+    /// map.entry_ref("b").and_modify(|e| *e += 1).or_insert(9);
+    /// assert_eq!(map, [("a".to_string(), 2), ("b".to_string(), 9)]);
+    /// ```
+    pub fn and_modify<F>(self, f: F) -> EntryRef<'a, 'b, K, Q, V, S>
+    where
+        F: FnOnce(&mut V),
+    {
+        match self {
+            EntryRef::Vacant(entry) => EntryRef::Vacant(entry),
+            EntryRef::Occupied(mut entry) => {
+                f(entry.get_mut());
+                EntryRef::Occupied(entry)
+            }
+        }
+    }
+}
+
+impl<'a, 'b, K, Q, V, S> EntryRef<'a, 'b, K, Q, V, S>
+where
+    V: 'a + Default,
+    Q: ToOwned<Owned = K> + ?Sized,
+    S: Storage<(K, V)>,
+{
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let mut map: Vec<(String, u32)> = Vec::new();
+    /// *map.entry_ref("a").or_default() += 1;
+    /// assert_eq!(map, [("a".to_string(), 1)]);
+    /// ```
+    pub fn or_default(self) -> &'a mut V {
+        self.or_insert_with(Default::default)
+    }
+}
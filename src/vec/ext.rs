@@ -1,13 +1,143 @@
-use std::borrow::Borrow;
-use std::fmt;
-use std::slice::{Iter, IterMut};
-use std::vec::IntoIter;
+use alloc::borrow::ToOwned;
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use core::fmt;
+#[cfg(feature = "std")]
+use core::hash::Hash;
+use core::iter::{Chain, FusedIterator};
+use core::mem;
+use core::slice;
 
-use crate::vec::{Entry, OccupiedEntry, VacantEntry};
+use crate::vec::{
+    AssocSliceExt, CursorMut, Entry, EntryRef, OccupiedEntry, OccupiedError, Storage, VacantEntry,
+    VacantEntryRef,
+};
+use crate::Equivalent;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
 
+/// An iterator over the key-value pairs of an associative array.
+///
+/// Returned by [`AssocExt::pairs`].
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Iter<'a, K: 'a, V: 'a> {
+    inner: slice::Iter<'a, (K, V)>,
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<(&'a K, &'a V)> {
+        self.inner.next().map(|(k, v)| (k, v))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, K, V> DoubleEndedIterator for Iter<'a, K, V> {
+    fn next_back(&mut self) -> Option<(&'a K, &'a V)> {
+        self.inner.next_back().map(|(k, v)| (k, v))
+    }
+}
+
+impl<K, V> ExactSizeIterator for Iter<'_, K, V> {
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let map = vec![("a", 1), ("b", 2), ("c", 3)];
+    /// let mut iter = map.pairs();
+    /// iter.next();
+    /// assert_eq!(iter.len(), 2);
+    /// ```
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+impl<K, V> FusedIterator for Iter<'_, K, V> {}
+
+impl<K: fmt::Debug, V: fmt::Debug> fmt::Debug for Iter<'_, K, V> {
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let map = vec![("a", 1), ("b", 2)];
+    /// assert_eq!(format!("{:?}", map.pairs()), r#"[("a", 1), ("b", 2)]"#);
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+impl<K, V> Clone for Iter<'_, K, V> {
+    fn clone(&self) -> Self {
+        Iter {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+/// A mutable iterator over the key-value pairs of an associative array.
+///
+/// Returned by [`AssocExt::pairs_mut`].
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct IterMut<'a, K: 'a, V: 'a> {
+    inner: slice::IterMut<'a, (K, V)>,
+}
+
+impl<'a, K, V> Iterator for IterMut<'a, K, V> {
+    type Item = (&'a K, &'a mut V);
+
+    fn next(&mut self) -> Option<(&'a K, &'a mut V)> {
+        self.inner.next().map(|(k, v)| (&*k, v))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, K, V> DoubleEndedIterator for IterMut<'a, K, V> {
+    fn next_back(&mut self) -> Option<(&'a K, &'a mut V)> {
+        self.inner.next_back().map(|(k, v)| (&*k, v))
+    }
+}
+
+impl<K, V> ExactSizeIterator for IterMut<'_, K, V> {
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+impl<K, V> FusedIterator for IterMut<'_, K, V> {}
+
+impl<K: fmt::Debug, V: fmt::Debug> fmt::Debug for IterMut<'_, K, V> {
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let mut map = vec![("a", 1), ("b", 2)];
+    /// assert_eq!(format!("{:?}", map.pairs_mut()), r#"[("a", 1), ("b", 2)]"#);
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.inner.as_slice().iter().map(|(k, v)| (k, v)))
+            .finish()
+    }
+}
+
+/// An iterator over the keys of an associative array.
+///
+/// Returned by [`AssocExt::keys`].
 #[must_use = "iterators are lazy and do nothing unless consumed"]
 pub struct Keys<'a, K: 'a, V: 'a> {
-    inner: Iter<'a, (K, V)>,
+    inner: slice::Iter<'a, (K, V)>,
+}
+
+impl<'a, K, V> Keys<'a, K, V> {
+    pub(crate) fn new(inner: slice::Iter<'a, (K, V)>) -> Keys<'a, K, V> {
+        Keys { inner }
+    }
 }
 
 impl<'a, K, V> Iterator for Keys<'a, K, V> {
@@ -16,8 +146,33 @@ impl<'a, K, V> Iterator for Keys<'a, K, V> {
     fn next(&mut self) -> Option<&'a K> {
         self.inner.next().map(|(k, _)| k)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, K, V> DoubleEndedIterator for Keys<'a, K, V> {
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let map = vec![("a", 1), ("b", 2), ("c", 3)];
+    /// let keys: Vec<_> = map.keys().rev().collect();
+    /// assert_eq!(keys, [&"c", &"b", &"a"]);
+    /// ```
+    fn next_back(&mut self) -> Option<&'a K> {
+        self.inner.next_back().map(|(k, _)| k)
+    }
+}
+
+impl<K, V> ExactSizeIterator for Keys<'_, K, V> {
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
 }
 
+impl<K, V> FusedIterator for Keys<'_, K, V> {}
+
 impl<K: fmt::Debug, V> fmt::Debug for Keys<'_, K, V> {
     /// ```rust
     /// use assoc::AssocExt;
@@ -38,17 +193,163 @@ impl<K, V> Clone for Keys<'_, K, V> {
     }
 }
 
+/// An iterator over the distinct keys of an associative array.
+///
+/// Returned by [`AssocExt::unique_keys`].
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct UniqueKeys<'a, K: 'a, V: 'a> {
+    vec: &'a [(K, V)],
+    index: usize,
+}
+
+impl<'a, K, V> Iterator for UniqueKeys<'a, K, V>
+where
+    K: PartialEq,
+{
+    type Item = &'a K;
+
+    fn next(&mut self) -> Option<&'a K> {
+        while let Some((k, _)) = self.vec.get(self.index) {
+            let seen = &self.vec[..self.index];
+            self.index += 1;
+            if !seen.iter().any(|(prev, _)| prev == k) {
+                return Some(k);
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.vec.len() - self.index;
+        (0, Some(remaining))
+    }
+}
+
+impl<K: fmt::Debug + PartialEq, V> fmt::Debug for UniqueKeys<'_, K, V> {
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let map = vec![("a", 1), ("b", 2), ("a", 3)];
+    /// assert_eq!(format!("{:?}", map.unique_keys()), r#"["a", "b"]"#);
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+impl<K, V> Clone for UniqueKeys<'_, K, V> {
+    fn clone(&self) -> Self {
+        UniqueKeys {
+            vec: self.vec,
+            index: self.index,
+        }
+    }
+}
+
+/// An iterator over the keys that occur more than once in an associative array.
+///
+/// Returned by [`AssocExt::duplicate_keys`].
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct DuplicateKeys<'a, K: 'a, V: 'a> {
+    vec: &'a [(K, V)],
+    index: usize,
+}
+
+impl<'a, K, V> Iterator for DuplicateKeys<'a, K, V>
+where
+    K: PartialEq,
+{
+    type Item = &'a K;
+
+    fn next(&mut self) -> Option<&'a K> {
+        while let Some((k, _)) = self.vec.get(self.index) {
+            let seen = &self.vec[..self.index];
+            self.index += 1;
+            if seen.iter().filter(|(prev, _)| prev == k).count() == 1 {
+                return Some(k);
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.vec.len() - self.index;
+        (0, Some(remaining))
+    }
+}
+
+impl<K: fmt::Debug + PartialEq, V> fmt::Debug for DuplicateKeys<'_, K, V> {
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let map = vec![("a", 1), ("b", 2), ("a", 3)];
+    /// assert_eq!(format!("{:?}", map.duplicate_keys()), r#"["a"]"#);
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+impl<K, V> Clone for DuplicateKeys<'_, K, V> {
+    fn clone(&self) -> Self {
+        DuplicateKeys {
+            vec: self.vec,
+            index: self.index,
+        }
+    }
+}
+
+/// An owning iterator over the keys of an associative array.
+///
+/// Returned by [`AssocExt::into_keys`].
 #[must_use = "iterators are lazy and do nothing unless consumed"]
-pub struct IntoKeys<K, V> {
-    inner: IntoIter<(K, V)>,
+pub struct IntoKeys<K, V, S = Vec<(K, V)>>
+where
+    S: IntoIterator<Item = (K, V)>,
+{
+    inner: S::IntoIter,
 }
 
-impl<K, V> Iterator for IntoKeys<K, V> {
+impl<K, V, S> Iterator for IntoKeys<K, V, S>
+where
+    S: IntoIterator<Item = (K, V)>,
+{
     type Item = K;
 
     fn next(&mut self) -> Option<K> {
         self.inner.next().map(|(k, _)| k)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<K, V, S> DoubleEndedIterator for IntoKeys<K, V, S>
+where
+    S: IntoIterator<Item = (K, V)>,
+    S::IntoIter: DoubleEndedIterator,
+{
+    fn next_back(&mut self) -> Option<K> {
+        self.inner.next_back().map(|(k, _)| k)
+    }
+}
+
+impl<K, V, S> ExactSizeIterator for IntoKeys<K, V, S>
+where
+    S: IntoIterator<Item = (K, V)>,
+    S::IntoIter: ExactSizeIterator,
+{
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+impl<K, V, S> FusedIterator for IntoKeys<K, V, S>
+where
+    S: IntoIterator<Item = (K, V)>,
+    S::IntoIter: FusedIterator,
+{
 }
 
 impl<K: fmt::Debug, V> fmt::Debug for IntoKeys<K, V> {
@@ -65,9 +366,18 @@ impl<K: fmt::Debug, V> fmt::Debug for IntoKeys<K, V> {
     }
 }
 
+/// An iterator over the values of an associative array.
+///
+/// Returned by [`AssocExt::values`].
 #[must_use = "iterators are lazy and do nothing unless consumed"]
 pub struct Values<'a, K: 'a, V: 'a> {
-    inner: Iter<'a, (K, V)>,
+    inner: slice::Iter<'a, (K, V)>,
+}
+
+impl<'a, K, V> Values<'a, K, V> {
+    pub(crate) fn new(inner: slice::Iter<'a, (K, V)>) -> Values<'a, K, V> {
+        Values { inner }
+    }
 }
 
 impl<'a, K, V> Iterator for Values<'a, K, V> {
@@ -76,8 +386,26 @@ impl<'a, K, V> Iterator for Values<'a, K, V> {
     fn next(&mut self) -> Option<&'a V> {
         self.inner.next().map(|(_, v)| v)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, K, V> DoubleEndedIterator for Values<'a, K, V> {
+    fn next_back(&mut self) -> Option<&'a V> {
+        self.inner.next_back().map(|(_, v)| v)
+    }
+}
+
+impl<K, V> ExactSizeIterator for Values<'_, K, V> {
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
 }
 
+impl<K, V> FusedIterator for Values<'_, K, V> {}
+
 impl<K, V: fmt::Debug> fmt::Debug for Values<'_, K, V> {
     /// ```rust
     /// use assoc::AssocExt;
@@ -98,9 +426,12 @@ impl<K, V> Clone for Values<'_, K, V> {
     }
 }
 
+/// A mutable iterator over the values of an associative array.
+///
+/// Returned by [`AssocExt::values_mut`].
 #[must_use = "iterators are lazy and do nothing unless consumed"]
 pub struct ValuesMut<'a, K: 'a, V: 'a> {
-    inner: IterMut<'a, (K, V)>,
+    inner: slice::IterMut<'a, (K, V)>,
 }
 
 impl<'a, K, V> Iterator for ValuesMut<'a, K, V> {
@@ -109,8 +440,26 @@ impl<'a, K, V> Iterator for ValuesMut<'a, K, V> {
     fn next(&mut self) -> Option<&'a mut V> {
         self.inner.next().map(|(_, v)| v)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, K, V> DoubleEndedIterator for ValuesMut<'a, K, V> {
+    fn next_back(&mut self) -> Option<&'a mut V> {
+        self.inner.next_back().map(|(_, v)| v)
+    }
 }
 
+impl<K, V> ExactSizeIterator for ValuesMut<'_, K, V> {
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+impl<K, V> FusedIterator for ValuesMut<'_, K, V> {}
+
 impl<K, V: fmt::Debug> fmt::Debug for ValuesMut<'_, K, V> {
     /// ```rust
     /// use assoc::AssocExt;
@@ -125,17 +474,65 @@ impl<K, V: fmt::Debug> fmt::Debug for ValuesMut<'_, K, V> {
     }
 }
 
+/// An owning iterator over the values of an associative array.
+///
+/// Returned by [`AssocExt::into_values`].
 #[must_use = "iterators are lazy and do nothing unless consumed"]
-pub struct IntoValues<K, V> {
-    inner: IntoIter<(K, V)>,
+pub struct IntoValues<K, V, S = Vec<(K, V)>>
+where
+    S: IntoIterator<Item = (K, V)>,
+{
+    inner: S::IntoIter,
 }
 
-impl<K, V> Iterator for IntoValues<K, V> {
+impl<K, V, S> Iterator for IntoValues<K, V, S>
+where
+    S: IntoIterator<Item = (K, V)>,
+{
     type Item = V;
 
     fn next(&mut self) -> Option<V> {
         self.inner.next().map(|(_, v)| v)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<K, V, S> DoubleEndedIterator for IntoValues<K, V, S>
+where
+    S: IntoIterator<Item = (K, V)>,
+    S::IntoIter: DoubleEndedIterator,
+{
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let map = vec![("a", 1), ("b", 2), ("c", 3)];
+    /// let mut values = map.into_values();
+    /// assert_eq!(values.next_back(), Some(3));
+    /// assert_eq!(values.len(), 2);
+    /// ```
+    fn next_back(&mut self) -> Option<V> {
+        self.inner.next_back().map(|(_, v)| v)
+    }
+}
+
+impl<K, V, S> ExactSizeIterator for IntoValues<K, V, S>
+where
+    S: IntoIterator<Item = (K, V)>,
+    S::IntoIter: ExactSizeIterator,
+{
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+impl<K, V, S> FusedIterator for IntoValues<K, V, S>
+where
+    S: IntoIterator<Item = (K, V)>,
+    S::IntoIter: FusedIterator,
+{
 }
 
 impl<K, V: fmt::Debug> fmt::Debug for IntoValues<K, V> {
@@ -152,24 +549,555 @@ impl<K, V: fmt::Debug> fmt::Debug for IntoValues<K, V> {
     }
 }
 
-/// A trait extension that allows vectors to be treated as associative arrays.
-pub trait AssocExt<K, V> {
-    /// Get a key's entry for in-place manipulation.
-    ///
+/// A draining iterator over the key-value pairs of an associative array.
+///
+/// Returned by [`AssocExt::drain`].
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Drain<'a, K: 'a, V: 'a, S = Vec<(K, V)>>
+where
+    S: Storage<(K, V)> + 'a,
+{
+    inner: S::Drain<'a>,
+}
+
+impl<'a, K, V, S> Iterator for Drain<'a, K, V, S>
+where
+    S: Storage<(K, V)> + 'a,
+{
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<(K, V)> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, K, V, S> DoubleEndedIterator for Drain<'a, K, V, S>
+where
+    S: Storage<(K, V)> + 'a,
+    S::Drain<'a>: DoubleEndedIterator,
+{
+    fn next_back(&mut self) -> Option<(K, V)> {
+        self.inner.next_back()
+    }
+}
+
+impl<'a, K, V, S> ExactSizeIterator for Drain<'a, K, V, S>
+where
+    S: Storage<(K, V)> + 'a,
+    S::Drain<'a>: ExactSizeIterator,
+{
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+impl<'a, K, V, S> FusedIterator for Drain<'a, K, V, S>
+where
+    S: Storage<(K, V)> + 'a,
+    S::Drain<'a>: FusedIterator,
+{
+}
+
+impl<K: fmt::Debug, V: fmt::Debug> fmt::Debug for Drain<'_, K, V> {
     /// ```rust
     /// use assoc::AssocExt;
     ///
-    /// let mut count = Vec::new();
-    /// for x in vec!["a", "b", "c", "b"] {
-    ///     *count.entry(x).or_insert(0) += 1;
-    /// }
-    /// assert_eq!(count.get(&"b"), Some(&2));
+    /// let mut map = vec![("a", 1), ("b", 2)];
+    /// assert_eq!(format!("{:?}", AssocExt::drain(&mut map)), r#"[("a", 1), ("b", 2)]"#);
     /// ```
-    fn entry(&mut self, key: K) -> Entry<K, V>;
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.inner.as_slice()).finish()
+    }
+}
 
-    /// Get a reference to the value associated with a key.
-    ///
-    /// ```rust
+/// An iterator that removes and yields the key-value pairs of an associative array that match a
+/// predicate.
+///
+/// Returned by [`AssocExt::extract_if`].
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ExtractIf<'a, K: 'a, V: 'a, F, S = Vec<(K, V)>>
+where
+    F: FnMut(&K, &mut V) -> bool,
+    S: Storage<(K, V)>,
+{
+    // The map is moved out into `rest` and the kept pairs are pushed back in order, so each pair
+    // is moved once instead of shifting the tail on every removal.
+    vec: &'a mut S,
+    rest: S::IntoIter,
+    // The pair being tested, kept here so it is put back if the predicate panics.
+    pending: Option<(K, V)>,
+    pred: F,
+}
+
+impl<'a, K, V, F, S> ExtractIf<'a, K, V, F, S>
+where
+    F: FnMut(&K, &mut V) -> bool,
+    S: Storage<(K, V)> + Default,
+{
+    fn new(vec: &'a mut S, pred: F) -> ExtractIf<'a, K, V, F, S> {
+        let pairs = mem::take(vec);
+        Storage::reserve(vec, pairs.as_slice().len());
+        ExtractIf {
+            vec,
+            rest: pairs.into_iter(),
+            pending: None,
+            pred,
+        }
+    }
+}
+
+impl<K, V, F, S> Iterator for ExtractIf<'_, K, V, F, S>
+where
+    F: FnMut(&K, &mut V) -> bool,
+    S: Storage<(K, V)>,
+{
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<(K, V)> {
+        for pair in self.rest.by_ref() {
+            let (k, v) = self.pending.insert(pair);
+            let matched = (self.pred)(k, v);
+            let pair = self.pending.take()?;
+            if matched {
+                return Some(pair);
+            }
+            let len = self.vec.as_slice().len();
+            self.vec.insert(len, pair);
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.rest.size_hint().1)
+    }
+}
+
+impl<K, V, F, S> Drop for ExtractIf<'_, K, V, F, S>
+where
+    F: FnMut(&K, &mut V) -> bool,
+    S: Storage<(K, V)>,
+{
+    fn drop(&mut self) {
+        for pair in self.pending.take().into_iter().chain(self.rest.by_ref()) {
+            let len = self.vec.as_slice().len();
+            self.vec.insert(len, pair);
+        }
+    }
+}
+
+impl<K: fmt::Debug, V: fmt::Debug, F> fmt::Debug for ExtractIf<'_, K, V, F>
+where
+    F: FnMut(&K, &mut V) -> bool,
+{
+    /// Shows the pairs that have not been visited yet.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let mut map = vec![("a", 1), ("b", 2), ("c", 3)];
+    /// let mut iter = AssocExt::extract_if(&mut map, |_, v| *v == 1);
+    /// assert_eq!(iter.next(), Some(("a", 1)));
+    /// assert_eq!(format!("{:?}", iter), r#"[("b", 2), ("c", 3)]"#);
+    /// drop(iter);
+    /// assert_eq!(map, [("b", 2), ("c", 3)]);
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.rest.as_slice()).finish()
+    }
+}
+
+/// An iterator over the key-value pairs that are in the first associative array but not in the
+/// second.
+///
+/// Returned by [`AssocExt::difference`].
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Difference<'a, K: 'a, V: 'a> {
+    inner: slice::Iter<'a, (K, V)>,
+    other: &'a [(K, V)],
+}
+
+impl<'a, K, V> Iterator for Difference<'a, K, V>
+where
+    K: PartialEq,
+{
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<(&'a K, &'a V)> {
+        let other = self.other;
+        self.inner
+            .find(|(k, _)| !other.iter().any(|(o, _)| o == k))
+            .map(|(k, v)| (k, v))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.inner.size_hint().1)
+    }
+}
+
+impl<K: fmt::Debug + PartialEq, V: fmt::Debug> fmt::Debug for Difference<'_, K, V> {
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let a = vec![("a", 1), ("b", 2)];
+    /// let b = vec![("b", 3)];
+    /// assert_eq!(format!("{:?}", a.difference(&b)), r#"[("a", 1)]"#);
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+impl<K, V> Clone for Difference<'_, K, V> {
+    fn clone(&self) -> Self {
+        Difference {
+            inner: self.inner.clone(),
+            other: self.other,
+        }
+    }
+}
+
+/// An iterator over the key-value pairs that are in both associative arrays, with values from the first.
+///
+/// Returned by [`AssocExt::intersection`].
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Intersection<'a, K: 'a, V: 'a> {
+    inner: slice::Iter<'a, (K, V)>,
+    other: &'a [(K, V)],
+}
+
+impl<'a, K, V> Iterator for Intersection<'a, K, V>
+where
+    K: PartialEq,
+{
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<(&'a K, &'a V)> {
+        let other = self.other;
+        self.inner
+            .find(|(k, _)| other.iter().any(|(o, _)| o == k))
+            .map(|(k, v)| (k, v))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.inner.size_hint().1)
+    }
+}
+
+impl<K: fmt::Debug + PartialEq, V: fmt::Debug> fmt::Debug for Intersection<'_, K, V> {
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let a = vec![("a", 1), ("b", 2)];
+    /// let b = vec![("b", 3)];
+    /// assert_eq!(format!("{:?}", a.intersection(&b)), r#"[("b", 2)]"#);
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+impl<K, V> Clone for Intersection<'_, K, V> {
+    fn clone(&self) -> Self {
+        Intersection {
+            inner: self.inner.clone(),
+            other: self.other,
+        }
+    }
+}
+
+/// An iterator over the key-value pairs that are in either associative array, with values from the first where the key is in both.
+///
+/// Returned by [`AssocExt::union`].
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Union<'a, K: 'a, V: 'a> {
+    inner: Chain<Iter<'a, K, V>, Difference<'a, K, V>>,
+}
+
+impl<'a, K, V> Iterator for Union<'a, K, V>
+where
+    K: PartialEq,
+{
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<(&'a K, &'a V)> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<K: fmt::Debug + PartialEq, V: fmt::Debug> fmt::Debug for Union<'_, K, V> {
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let a = vec![("a", 1), ("b", 2)];
+    /// let b = vec![("b", 4), ("c", 3)];
+    /// assert_eq!(format!("{:?}", a.union(&b)), r#"[("a", 1), ("b", 2), ("c", 3)]"#);
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+impl<K, V> Clone for Union<'_, K, V> {
+    fn clone(&self) -> Self {
+        Union {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+/// An iterator over the key-value pairs that are in exactly one of the two associative arrays.
+///
+/// Returned by [`AssocExt::symmetric_difference`].
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct SymmetricDifference<'a, K: 'a, V: 'a> {
+    inner: Chain<Difference<'a, K, V>, Difference<'a, K, V>>,
+}
+
+impl<'a, K, V> Iterator for SymmetricDifference<'a, K, V>
+where
+    K: PartialEq,
+{
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<(&'a K, &'a V)> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<K: fmt::Debug + PartialEq, V: fmt::Debug> fmt::Debug for SymmetricDifference<'_, K, V> {
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let a = vec![("a", 1), ("b", 2)];
+    /// let b = vec![("b", 4), ("c", 3)];
+    /// assert_eq!(format!("{:?}", a.symmetric_difference(&b)), r#"[("a", 1), ("c", 3)]"#);
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+impl<K, V> Clone for SymmetricDifference<'_, K, V> {
+    fn clone(&self) -> Self {
+        SymmetricDifference {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+/// The differences between two associative arrays, by key.
+///
+/// Returned by [`AssocExt::diff`].
+#[must_use = "a diff does nothing unless its iterators are consumed"]
+pub struct Diff<'a, K: 'a, V: 'a> {
+    old: &'a [(K, V)],
+    new: &'a [(K, V)],
+}
+
+impl<'a, K, V> Diff<'a, K, V>
+where
+    K: PartialEq,
+{
+    /// Get an iterator over the key-value pairs of the new map whose keys are not in the old map.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let old = vec![("a", 1)];
+    /// let new = vec![("a", 1), ("b", 2)];
+    /// assert_eq!(old.diff(&new).added().collect::<Vec<_>>(), [(&"b", &2)]);
+    /// ```
+    pub fn added(&self) -> Difference<'a, K, V> {
+        Difference {
+            inner: self.new.iter(),
+            other: self.old,
+        }
+    }
+
+    /// Get an iterator over the key-value pairs of the old map whose keys are not in the new map.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let old = vec![("a", 1), ("b", 2)];
+    /// let new = vec![("a", 1)];
+    /// assert_eq!(old.diff(&new).removed().collect::<Vec<_>>(), [(&"b", &2)]);
+    /// ```
+    pub fn removed(&self) -> Difference<'a, K, V> {
+        Difference {
+            inner: self.old.iter(),
+            other: self.new,
+        }
+    }
+
+    /// Get an iterator over the keys that are in both maps with different values, along with the
+    /// old and the new value.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let old = vec![("a", 1), ("b", 2)];
+    /// let new = vec![("b", 3), ("a", 1)];
+    /// assert_eq!(old.diff(&new).changed().collect::<Vec<_>>(), [(&"b", &2, &3)]);
+    /// ```
+    pub fn changed(&self) -> Changed<'a, K, V>
+    where
+        V: PartialEq,
+    {
+        Changed {
+            inner: self.old.iter(),
+            new: self.new,
+        }
+    }
+
+    /// Check whether the two maps have the same keys with the same values, regardless of order.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let old = vec![("a", 1), ("b", 2)];
+    /// assert!(old.diff(&[("b", 2), ("a", 1)]).is_empty());
+    /// assert!(!old.diff(&[("a", 1)]).is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool
+    where
+        V: PartialEq,
+    {
+        self.added().next().is_none()
+            && self.removed().next().is_none()
+            && self.changed().next().is_none()
+    }
+}
+
+impl<K: fmt::Debug + PartialEq, V: fmt::Debug + PartialEq> fmt::Debug for Diff<'_, K, V> {
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let old = vec![("a", 1), ("b", 2)];
+    /// let new = vec![("b", 3), ("c", 4)];
+    /// assert_eq!(
+    ///     format!("{:?}", old.diff(&new)),
+    ///     r#"Diff { added: [("c", 4)], removed: [("a", 1)], changed: [("b", 2, 3)] }"#,
+    /// );
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Diff")
+            .field("added", &self.added())
+            .field("removed", &self.removed())
+            .field("changed", &self.changed())
+            .finish()
+    }
+}
+
+impl<K, V> Clone for Diff<'_, K, V> {
+    fn clone(&self) -> Self {
+        Diff {
+            old: self.old,
+            new: self.new,
+        }
+    }
+}
+
+/// An iterator over the keys whose values differ between two associative arrays, with the old
+/// and the new value.
+///
+/// Returned by [`Diff::changed`].
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Changed<'a, K: 'a, V: 'a> {
+    inner: slice::Iter<'a, (K, V)>,
+    new: &'a [(K, V)],
+}
+
+impl<'a, K, V> Iterator for Changed<'a, K, V>
+where
+    K: PartialEq,
+    V: PartialEq,
+{
+    type Item = (&'a K, &'a V, &'a V);
+
+    fn next(&mut self) -> Option<(&'a K, &'a V, &'a V)> {
+        let new = self.new;
+        self.inner.find_map(|(k, old)| {
+            let (_, v) = new.iter().find(|(n, _)| n == k)?;
+            if v != old {
+                Some((k, old, v))
+            } else {
+                None
+            }
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.inner.size_hint().1)
+    }
+}
+
+impl<K: fmt::Debug + PartialEq, V: fmt::Debug + PartialEq> fmt::Debug for Changed<'_, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+impl<K, V> Clone for Changed<'_, K, V> {
+    fn clone(&self) -> Self {
+        Changed {
+            inner: self.inner.clone(),
+            new: self.new,
+        }
+    }
+}
+
+/// Which pair to keep when removing duplicate keys with [`AssocExt::dedup_keys`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum KeepPolicy {
+    /// Keep the first pair with each key, which is the one [`AssocExt::get`] sees.
+    First,
+
+    /// Keep the last pair with each key.
+    Last,
+}
+
+/// Which pair to evict when inserting into a full map with [`AssocExt::insert_bounded`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EvictPolicy {
+    /// Evict the pair that was inserted first. Updating a key does not change its age.
+    Oldest,
+
+    /// Evict the pair that was used least recently. Updating a key counts as a use, and so does
+    /// looking it up with [`AssocExt::get_touch`].
+    LeastRecentlyUsed,
+}
+
+/// A trait extension that allows vectors to be treated as associative arrays.
+pub trait AssocExt<K, V>: Storage<(K, V)> {
+    /// Get a key's entry for in-place manipulation.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let mut count = Vec::new();
+    /// for x in vec!["a", "b", "c", "b"] {
+    ///     *count.entry(x).or_insert(0) += 1;
+    /// }
+    /// assert_eq!(count.get(&"b"), Some(&2));
+    /// ```
+    fn entry(&mut self, key: K) -> Entry<'_, K, V, Self>;
+
+    /// Get a reference to the value associated with a key.
+    ///
+    /// ```rust
     /// use assoc::AssocExt;
     ///
     /// let map = vec![("a", 1), ("b", 2)];
@@ -177,8 +1105,7 @@ pub trait AssocExt<K, V> {
     /// ```
     fn get<Q>(&self, key: &Q) -> Option<&V>
     where
-        K: Borrow<Q>,
-        Q: PartialEq + ?Sized;
+        Q: Equivalent<K> + ?Sized;
 
     /// Get a mutable reference to the value associated with a key.
     ///
@@ -191,10 +1118,35 @@ pub trait AssocExt<K, V> {
     /// ```
     fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
     where
-        K: Borrow<Q>,
-        Q: PartialEq + ?Sized;
+        Q: Equivalent<K> + ?Sized;
 
-    /// Insert a key-value pair into the associative array.
+    /// Get the key-value pair associated with a key.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let map = vec![("a", 1), ("b", 2)];
+    /// assert_eq!(map.get_key_value(&"a"), Some((&"a", &1)));
+    /// assert_eq!(map.get_key_value(&"c"), None);
+    /// ```
+    fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        Q: Equivalent<K> + ?Sized;
+
+    /// Check whether the map contains a value for a key.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let map = vec![("a", 1), ("b", 2)];
+    /// assert!(map.contains_key(&"a"));
+    /// assert!(!map.contains_key(&"c"));
+    /// ```
+    fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        Q: Equivalent<K> + ?Sized;
+
+    /// Insert a key-value pair into the associative array.
     /// If the map previously had the key, then the old value is returned. Otherwise, `None` is
     /// returned.
     ///
@@ -207,296 +1159,2236 @@ pub trait AssocExt<K, V> {
     /// ```
     fn insert(&mut self, key: K, value: V) -> Option<V>;
 
-    /// Remove a key from the map, returning the value if it was previously in the map.
+    /// Remove a key from the map, returning the value if it was previously in the map.
+    ///
+    /// The last pair in the vector is moved into the removed pair's position, so this takes
+    /// constant time after the lookup but does not preserve order.
+    /// Use [`AssocExt::shift_remove`] to preserve order.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let mut map = vec![("a", 1)];
+    /// assert_eq!(AssocExt::remove(&mut map, "a"), Some(1));
+    /// assert_eq!(AssocExt::remove(&mut map, "a"), None);
+    /// ```
+    fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        Q: Equivalent<K> + ?Sized;
+
+    /// Remove a key from the map, returning the stored key and value if the key was previously in
+    /// the map.
+    ///
+    /// Like [`AssocExt::remove`], this does not preserve order.
+    /// Use [`AssocExt::shift_remove_entry`] to preserve order.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let mut map = vec![("a", 1)];
+    /// assert_eq!(map.remove_entry(&"a"), Some(("a", 1)));
+    /// assert_eq!(map.remove_entry(&"a"), None);
+    /// ```
+    fn remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        Q: Equivalent<K> + ?Sized;
+
+    /// Remove a key from the map, returning the value if it was previously in the map.
+    ///
+    /// All pairs after the removed pair are shifted down by one, so this preserves the order of
+    /// the remaining pairs at the cost of a linear shift.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let mut map = vec![("a", 1), ("b", 2), ("c", 3)];
+    /// assert_eq!(map.shift_remove(&"a"), Some(1));
+    /// assert_eq!(map, [("b", 2), ("c", 3)]);
+    /// ```
+    fn shift_remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        Q: Equivalent<K> + ?Sized;
+
+    /// Remove a key from the map, returning the stored key and value if the key was previously in
+    /// the map.
+    /// This preserves the order of the remaining pairs.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let mut map = vec![("a", 1), ("b", 2), ("c", 3)];
+    /// assert_eq!(map.shift_remove_entry(&"a"), Some(("a", 1)));
+    /// assert_eq!(map, [("b", 2), ("c", 3)]);
+    /// ```
+    fn shift_remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        Q: Equivalent<K> + ?Sized;
+
+    /// Retain only the pairs specified by the predicate.
+    /// In other words, remove all pairs `(k, v)` for which `f(&k, &mut v)` returns `false`.
+    /// The remaining pairs keep their relative order.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let mut map = vec![("a", 1), ("b", 2), ("c", 3), ("d", 4)];
+    /// AssocExt::retain(&mut map, |_, v| *v % 2 == 0);
+    /// assert_eq!(map, [("b", 2), ("d", 4)]);
+    /// ```
+    fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&K, &mut V) -> bool;
+
+    /// Remove all pairs from the map, returning them as an iterator in order.
+    /// The map is empty after the iterator is dropped, even if it was not fully consumed.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let mut map = vec![("a", 1), ("b", 2)];
+    /// let drained: Vec<(&str, i32)> = AssocExt::drain(&mut map).collect();
+    /// assert_eq!(drained, [("a", 1), ("b", 2)]);
+    /// assert!(map.is_empty());
+    /// ```
+    fn drain(&mut self) -> Drain<'_, K, V, Self>;
+
+    /// Create an iterator that removes and yields the pairs for which `pred(&k, &mut v)` returns
+    /// `true`.
+    /// Pairs for which the predicate returns `false` stay in the map in their original order.
+    ///
+    /// If the iterator is dropped before it is fully consumed, the pairs that have not been
+    /// visited yet are kept. The map is rebuilt in a single pass, so extracting any number of
+    /// pairs takes linear time.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let mut map = vec![("a", 1), ("b", 2), ("c", 3), ("d", 4)];
+    /// let evens: Vec<(&str, i32)> = AssocExt::extract_if(&mut map, |_, v| *v % 2 == 0).collect();
+    /// assert_eq!(evens, [("b", 2), ("d", 4)]);
+    /// assert_eq!(map, [("a", 1), ("c", 3)]);
+    /// ```
+    fn extract_if<F>(&mut self, pred: F) -> ExtractIf<'_, K, V, F, Self>
+    where
+        F: FnMut(&K, &mut V) -> bool;
+
+    /// Get a reference to the value of the first pair whose key satisfies a predicate.
+    /// This is useful when constructing a key to compare against is impossible or expensive.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let map = vec![("apple", 1), ("banana", 2)];
+    /// assert_eq!(map.get_by(|k| k.starts_with('b')), Some(&2));
+    /// assert_eq!(map.get_by(|k| k.is_empty()), None);
+    /// ```
+    fn get_by<P>(&self, pred: P) -> Option<&V>
+    where
+        P: FnMut(&K) -> bool;
+
+    /// Get a mutable reference to the value of the first pair whose key satisfies a predicate.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let mut map = vec![("apple", 1), ("banana", 2)];
+    /// *map.get_mut_by(|k| k.starts_with('a')).unwrap() += 10;
+    /// assert_eq!(map.get(&"apple"), Some(&11));
+    /// ```
+    fn get_mut_by<P>(&mut self, pred: P) -> Option<&mut V>
+    where
+        P: FnMut(&K) -> bool;
+
+    /// Get the entry of the first pair whose key satisfies a predicate.
+    /// If no key satisfies the predicate, `make_key` is called to create the key of the vacant
+    /// entry, so the key is only constructed when it is needed.
+    ///
+    /// `make_key` should create a key that satisfies `pred`; otherwise a later lookup using the
+    /// same predicate will not find the inserted pair.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let mut map = vec![("apple".to_string(), 1)];
+    /// *map.entry_by(|k| k == "apple", || "apple".to_string()).or_insert(0) += 1;
+    /// *map.entry_by(|k| k == "cherry", || "cherry".to_string()).or_insert(0) += 1;
+    /// assert_eq!(map, [("apple".to_string(), 2), ("cherry".to_string(), 1)]);
+    /// ```
+    fn entry_by<P, F>(&mut self, pred: P, make_key: F) -> Entry<'_, K, V, Self>
+    where
+        P: FnMut(&K) -> bool,
+        F: FnOnce() -> K;
+
+    /// Get a key's entry for in-place manipulation, looking it up by a borrowed key.
+    /// The key is only converted into an owned key with [`ToOwned`] if a value is inserted into a
+    /// vacant entry, so occupied entries never clone the key.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let mut count: Vec<(String, u32)> = Vec::new();
+    /// for word in "a b a c a".split(' ') {
+    ///     *count.entry_ref(word).or_insert(0) += 1;
+    /// }
+    /// assert_eq!(count.get("a"), Some(&3));
+    /// assert_eq!(count.len(), 3);
+    /// ```
+    fn entry_ref<'a, 'b, Q>(&'a mut self, key: &'b Q) -> EntryRef<'a, 'b, K, Q, V, Self>
+    where
+        Q: Equivalent<K> + ToOwned<Owned = K> + ?Sized;
+
+    /// Get the first key-value pair in the map, which is the oldest pair if the map has only been
+    /// grown by insertion.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let map = vec![("a", 1), ("b", 2)];
+    /// assert_eq!(map.first_key_value(), Some((&"a", &1)));
+    /// ```
+    fn first_key_value(&self) -> Option<(&K, &V)>;
+
+    /// Get the last key-value pair in the map.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let map = vec![("a", 1), ("b", 2)];
+    /// assert_eq!(map.last_key_value(), Some((&"b", &2)));
+    /// ```
+    fn last_key_value(&self) -> Option<(&K, &V)>;
+
+    /// Remove and return the first key-value pair in the map.
+    /// The remaining pairs are shifted down to preserve their order.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let mut map = vec![("a", 1), ("b", 2), ("c", 3)];
+    /// assert_eq!(map.pop_first(), Some(("a", 1)));
+    /// assert_eq!(map, [("b", 2), ("c", 3)]);
+    /// ```
+    fn pop_first(&mut self) -> Option<(K, V)>;
+
+    /// Remove and return the last key-value pair in the map.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let mut map = vec![("a", 1), ("b", 2)];
+    /// assert_eq!(map.pop_last(), Some(("b", 2)));
+    /// assert_eq!(map, [("a", 1)]);
+    /// ```
+    fn pop_last(&mut self) -> Option<(K, V)>;
+
+    /// Get the entry of the first pair in the map for in-place manipulation.
+    ///
+    /// Note that [`OccupiedEntry::remove`] moves the last pair to the front; use
+    /// [`OccupiedEntry::shift_remove`] to preserve order.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let mut map = vec![("a", 1), ("b", 2), ("c", 3)];
+    /// if let Some(mut entry) = map.first_entry() {
+    ///     *entry.get_mut() += 10;
+    /// }
+    /// assert_eq!(map.first_entry().unwrap().shift_remove(), 11);
+    /// assert_eq!(map, [("b", 2), ("c", 3)]);
+    /// ```
+    fn first_entry(&mut self) -> Option<OccupiedEntry<'_, K, V, Self>>;
+
+    /// Get the entry of the last pair in the map for in-place manipulation.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let mut map = vec![("a", 1), ("b", 2)];
+    /// let entry = map.last_entry().unwrap();
+    /// assert_eq!(entry.key(), &"b");
+    /// assert_eq!(entry.remove(), 2);
+    /// assert_eq!(map, [("a", 1)]);
+    /// ```
+    fn last_entry(&mut self) -> Option<OccupiedEntry<'_, K, V, Self>>;
+
+    /// Get the key-value pair at a position in the vector.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let map = vec![("a", 1), ("b", 2)];
+    /// assert_eq!(map.get_index(1), Some((&"b", &2)));
+    /// assert_eq!(map.get_index(2), None);
+    /// ```
+    fn get_index(&self, index: usize) -> Option<(&K, &V)>;
+
+    /// Get the key and a mutable reference to the value at a position in the vector.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let mut map = vec![("a", 1), ("b", 2)];
+    /// if let Some((_, v)) = map.get_index_mut(0) {
+    ///     *v += 10;
+    /// }
+    /// assert_eq!(map, [("a", 11), ("b", 2)]);
+    /// ```
+    fn get_index_mut(&mut self, index: usize) -> Option<(&K, &mut V)>;
+
+    /// Get the position of a key in the vector.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let map = vec![("a", 1), ("b", 2)];
+    /// assert_eq!(map.index_of(&"b"), Some(1));
+    /// assert_eq!(map.index_of(&"c"), None);
+    /// ```
+    fn index_of<Q>(&self, key: &Q) -> Option<usize>
+    where
+        Q: Equivalent<K> + ?Sized;
+
+    /// Swap the positions of two key-value pairs.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` is out of bounds.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let mut map = vec![("a", 1), ("b", 2), ("c", 3)];
+    /// map.swap_indices(0, 2);
+    /// assert_eq!(map, [("c", 3), ("b", 2), ("a", 1)]);
+    /// ```
+    fn swap_indices(&mut self, a: usize, b: usize);
+
+    /// Insert a key-value pair into the map if the key is not already present, and return a mutable
+    /// reference to the inserted value.
+    /// If the key is already present, nothing is updated, and an error containing the occupied
+    /// entry and the rejected value is returned.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let mut map = vec![("a", 1)];
+    /// assert_eq!(map.try_insert("b", 2).unwrap(), &2);
+    ///
+    /// let err = map.try_insert("a", 3).unwrap_err();
+    /// assert_eq!(err.entry.get(), &1);
+    /// assert_eq!(err.value, 3);
+    /// assert_eq!(map, [("a", 1), ("b", 2)]);
+    /// ```
+    fn try_insert(&mut self, key: K, value: V) -> Result<&mut V, OccupiedError<'_, K, V, Self>>;
+
+    /// Insert a key-value pair into the map, using `resolve` to combine the values if the key is
+    /// already present, and return a mutable reference to the stored value.
+    /// `resolve` is called with the existing value and the new value.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let mut counts = vec![("a", 1)];
+    /// counts.insert_with("a", 5, |old, new| *old += new);
+    /// counts.insert_with("b", 2, |old, new| *old += new);
+    /// assert_eq!(counts, [("a", 6), ("b", 2)]);
+    /// ```
+    fn insert_with<F>(&mut self, key: K, value: V, resolve: F) -> &mut V
+    where
+        F: FnOnce(&mut V, V);
+
+    /// Insert all the pairs from `other` into the map, using `resolve` to combine the values of
+    /// keys that are already present.
+    /// `resolve` is called with the existing value and the new value.
+    /// New keys are appended in the order they appear in `other`.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let mut shard = vec![("a", 1), ("b", 2)];
+    /// let other = vec![("b", 3), ("c", 4)];
+    /// shard.merge(other, |old, new| *old += new);
+    /// assert_eq!(shard, [("a", 1), ("b", 5), ("c", 4)]);
+    /// ```
+    fn merge<I, F>(&mut self, other: I, resolve: F)
+    where
+        I: IntoIterator<Item = (K, V)>,
+        F: FnMut(&mut V, V);
+
+    /// Insert the key-value pairs of an iterator into the map, replacing the values of keys that
+    /// are already present. Returns the number of values that were replaced.
+    ///
+    /// Unlike [`Extend::extend`] on the vector, this never introduces duplicate keys. New keys are
+    /// appended in iteration order, and capacity for them is reserved up front from the
+    /// iterator's size hint.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let mut map = vec![("a", 1), ("b", 2)];
+    /// assert_eq!(map.extend_replace(vec![("b", 20), ("c", 30), ("c", 31)]), 2);
+    /// assert_eq!(map, [("a", 1), ("b", 20), ("c", 31)]);
+    /// ```
+    fn extend_replace<I>(&mut self, iter: I) -> usize
+    where
+        I: IntoIterator<Item = (K, V)>;
+
+    /// Insert the key-value pairs of an iterator into the map, skipping keys that are already
+    /// present. Returns the number of pairs that were skipped.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let mut map = vec![("a", 1), ("b", 2)];
+    /// assert_eq!(map.extend_keep(vec![("b", 20), ("c", 30), ("c", 31)]), 2);
+    /// assert_eq!(map, [("a", 1), ("b", 2), ("c", 30)]);
+    /// ```
+    fn extend_keep<I>(&mut self, iter: I) -> usize
+    where
+        I: IntoIterator<Item = (K, V)>;
+
+    /// Get an iterator over the key-value pairs of the map whose keys are not in `other`.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let deployed = vec![("replicas", 3), ("timeout", 30), ("retries", 5)];
+    /// let desired = vec![("replicas", 4), ("timeout", 30)];
+    /// let extra: Vec<_> = deployed.difference(&desired).collect();
+    /// assert_eq!(extra, [(&"retries", &5)]);
+    /// ```
+    fn difference<'a>(&'a self, other: &'a [(K, V)]) -> Difference<'a, K, V>;
+
+    /// Get an iterator over the key-value pairs of the map whose keys are also in `other`. The
+    /// values are taken from this map.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let deployed = vec![("replicas", 3), ("timeout", 30), ("retries", 5)];
+    /// let desired = vec![("replicas", 4), ("timeout", 30)];
+    /// let drifted: Vec<_> = deployed
+    ///     .intersection(&desired)
+    ///     .filter(|(k, v)| desired.get(*k) != Some(v))
+    ///     .collect();
+    /// assert_eq!(drifted, [(&"replicas", &3)]);
+    /// ```
+    fn intersection<'a>(&'a self, other: &'a [(K, V)]) -> Intersection<'a, K, V>;
+
+    /// Get an iterator over the key-value pairs of the map, followed by the key-value pairs of
+    /// `other` whose keys are not in this map. Where a key is in both, the value is taken from
+    /// this map.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let overrides = vec![("timeout", 10)];
+    /// let defaults = vec![("timeout", 30), ("retries", 5)];
+    /// let config: Vec<_> = overrides.union(&defaults).collect();
+    /// assert_eq!(config, [(&"timeout", &10), (&"retries", &5)]);
+    /// ```
+    fn union<'a>(&'a self, other: &'a [(K, V)]) -> Union<'a, K, V>;
+
+    /// Get an iterator over the key-value pairs of the map whose keys are not in `other`, followed
+    /// by the key-value pairs of `other` whose keys are not in this map.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let a = vec![("a", 1), ("b", 2)];
+    /// let b = vec![("b", 3), ("c", 4)];
+    /// let only_one: Vec<_> = a.symmetric_difference(&b).collect();
+    /// assert_eq!(only_one, [(&"a", &1), (&"c", &4)]);
+    /// ```
+    fn symmetric_difference<'a>(&'a self, other: &'a [(K, V)]) -> SymmetricDifference<'a, K, V>;
+
+    /// Compare the map with a newer version of it, `other`. The returned [`Diff`] yields the
+    /// pairs that were added and removed, and the keys whose values changed.
+    ///
+    /// Keys are compared with [`PartialEq`], so the order of the pairs does not matter. Finding
+    /// changed values also requires `V: PartialEq`.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let deployed = vec![("replicas", 3), ("timeout", 30), ("retries", 5)];
+    /// let desired = vec![("timeout", 30), ("replicas", 4), ("region", 1)];
+    /// let diff = deployed.diff(&desired);
+    /// assert_eq!(diff.added().collect::<Vec<_>>(), [(&"region", &1)]);
+    /// assert_eq!(diff.removed().collect::<Vec<_>>(), [(&"retries", &5)]);
+    /// assert_eq!(diff.changed().collect::<Vec<_>>(), [(&"replicas", &3, &4)]);
+    /// ```
+    fn diff<'a>(&'a self, other: &'a [(K, V)]) -> Diff<'a, K, V>;
+
+    /// Get mutable references to the values of several keys at once. Each element of the result
+    /// is `None` if the corresponding key is not in the map.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any two of the keys refer to the same key-value pair.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let mut balances = vec![("alice", 100), ("bob", 20)];
+    /// if let [Some(from), Some(to)] = balances.get_disjoint_mut([&"alice", &"bob"]) {
+    ///     *from -= 30;
+    ///     *to += 30;
+    /// }
+    /// assert_eq!(balances, [("alice", 70), ("bob", 50)]);
+    /// assert!(matches!(balances.get_disjoint_mut([&"alice", &"carol"]), [Some(_), None]));
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use assoc::AssocExt;
+    ///
+    /// let mut balances = vec![("alice", 100)];
+    /// balances.get_disjoint_mut([&"alice", &"alice"]);
+    /// ```
+    fn get_disjoint_mut<Q, const N: usize>(&mut self, keys: [&Q; N]) -> [Option<&mut V>; N]
+    where
+        Q: Equivalent<K> + ?Sized;
+
+    /// Get a mutable reference to the value corresponding to the key, and move the pair to the
+    /// front of the map. The pairs before it are shifted back by one, so their relative order is
+    /// preserved.
+    ///
+    /// This is the classic move-to-front heuristic: with skewed access patterns, frequently used
+    /// keys stay near the front, where the linear search finds them quickly.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let mut map = vec![("a", 1), ("b", 2), ("c", 3)];
+    /// assert_eq!(map.get_promote(&"c"), Some(&mut 3));
+    /// assert_eq!(map, [("c", 3), ("a", 1), ("b", 2)]);
+    /// assert_eq!(map.get_promote(&"d"), None);
+    /// ```
+    fn get_promote<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        Q: Equivalent<K> + ?Sized;
+
+    /// Get a mutable reference to the value corresponding to the key, and move the pair to the
+    /// back of the map. The pairs after it are shifted forward by one, so their relative order is
+    /// preserved.
+    ///
+    /// This marks the pair as the most recently used one for
+    /// [`EvictPolicy::LeastRecentlyUsed`].
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let mut map = vec![("a", 1), ("b", 2), ("c", 3)];
+    /// assert_eq!(map.get_touch(&"a"), Some(&mut 1));
+    /// assert_eq!(map, [("b", 2), ("c", 3), ("a", 1)]);
+    /// assert_eq!(map.get_touch(&"d"), None);
+    /// ```
+    fn get_touch<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        Q: Equivalent<K> + ?Sized;
+
+    /// Insert a key-value pair into a map that should hold at most `max_len` pairs, evicting
+    /// pairs according to `policy` if the map is full. This turns the vector into a small cache.
+    ///
+    /// The map is kept ordered from the oldest pair to the newest, like a map grown by
+    /// [`AssocExt::insert`]: new keys are appended at the back, and pairs are evicted from the
+    /// front. With [`EvictPolicy::LeastRecentlyUsed`], updating a key also moves it to the back,
+    /// and lookups should use [`AssocExt::get_touch`] so they count as uses too.
+    ///
+    /// If the key is already present, its value is replaced and the key and old value are
+    /// returned. The key is not updated, and no pairs are evicted, even if the map holds more than
+    /// `max_len` pairs.
+    ///
+    /// Otherwise, pairs are evicted from the front until there is room for the new pair. If more
+    /// than one pair has to be evicted because the map was already over `max_len`, only the last
+    /// one evicted is returned, and the others are dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug builds if `max_len` is zero. In release builds, nothing is inserted and the
+    /// new pair is returned.
+    ///
+    /// ```rust
+    /// use assoc::vec::EvictPolicy;
+    /// use assoc::AssocExt;
+    ///
+    /// let mut cache = Vec::new();
+    /// cache.insert_bounded("a", 1, 2, EvictPolicy::LeastRecentlyUsed);
+    /// cache.insert_bounded("b", 2, 2, EvictPolicy::LeastRecentlyUsed);
+    /// assert_eq!(cache.get_touch(&"a"), Some(&mut 1));
+    /// assert_eq!(
+    ///     cache.insert_bounded("c", 3, 2, EvictPolicy::LeastRecentlyUsed),
+    ///     Some(("b", 2)),
+    /// );
+    /// assert_eq!(cache, [("a", 1), ("c", 3)]);
+    ///
+    /// let mut cache = Vec::new();
+    /// cache.insert_bounded("a", 1, 2, EvictPolicy::Oldest);
+    /// cache.insert_bounded("b", 2, 2, EvictPolicy::Oldest);
+    /// assert_eq!(cache.insert_bounded("a", 10, 2, EvictPolicy::Oldest), Some(("a", 1)));
+    /// assert_eq!(
+    ///     cache.insert_bounded("c", 3, 2, EvictPolicy::Oldest),
+    ///     Some(("a", 10)),
+    /// );
+    /// assert_eq!(cache, [("b", 2), ("c", 3)]);
+    /// ```
+    ///
+    /// A map that is already over the bound only shrinks when a new key is inserted:
+    ///
+    /// ```rust
+    /// use assoc::vec::EvictPolicy;
+    /// use assoc::AssocExt;
+    ///
+    /// let mut cache = vec![("a", 1), ("b", 2), ("c", 3)];
+    /// assert_eq!(cache.insert_bounded("a", 10, 1, EvictPolicy::Oldest), Some(("a", 1)));
+    /// assert_eq!(cache, [("a", 10), ("b", 2), ("c", 3)]);
+    ///
+    /// assert_eq!(cache.insert_bounded("d", 4, 1, EvictPolicy::Oldest), Some(("c", 3)));
+    /// assert_eq!(cache, [("d", 4)]);
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use assoc::vec::EvictPolicy;
+    /// use assoc::AssocExt;
+    ///
+    /// let mut cache = vec![("a", 1)];
+    /// cache.insert_bounded("b", 2, 0, EvictPolicy::Oldest);
+    /// ```
+    fn insert_bounded(
+        &mut self,
+        key: K,
+        value: V,
+        max_len: usize,
+        policy: EvictPolicy,
+    ) -> Option<(K, V)>;
+
+    /// Get a cursor pointing at the first pair of the map, for editing the map in a single pass.
+    /// See [`CursorMut`] for an example.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let mut map = vec![("a", 1), ("b", 2)];
+    /// let mut cursor = map.cursor_mut();
+    /// cursor.move_next();
+    /// if let Some(v) = cursor.value_mut() {
+    ///     *v += 10;
+    /// }
+    /// assert_eq!(map, [("a", 1), ("b", 12)]);
+    /// ```
+    fn cursor_mut(&mut self) -> CursorMut<'_, K, V, Self>;
+
+    /// Check whether any key occurs more than once in the vector.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// assert!(!vec![("a", 1), ("b", 2)].has_duplicate_keys());
+    /// assert!(vec![("a", 1), ("b", 2), ("a", 3)].has_duplicate_keys());
+    /// ```
+    fn has_duplicate_keys(&self) -> bool;
+
+    /// Get an iterator over the keys that occur more than once in the vector. Each such key is
+    /// yielded once, in order of its second occurrence.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let map = vec![("a", 1), ("b", 2), ("b", 3), ("a", 4), ("a", 5)];
+    /// let duplicates: Vec<&str> = map.duplicate_keys().copied().collect();
+    /// assert_eq!(duplicates, ["b", "a"]);
+    /// ```
+    fn duplicate_keys(&self) -> DuplicateKeys<'_, K, V>;
+
+    /// Remove pairs with duplicate keys, keeping either the first or the last pair with each key
+    /// according to `policy`. The remaining pairs keep their relative order.
+    ///
+    /// ```rust
+    /// use assoc::vec::KeepPolicy;
+    /// use assoc::AssocExt;
+    ///
+    /// let mut map = vec![("a", 1), ("b", 2), ("a", 3)];
+    /// map.dedup_keys(KeepPolicy::First);
+    /// assert_eq!(map, [("a", 1), ("b", 2)]);
+    ///
+    /// let mut map = vec![("a", 1), ("b", 2), ("a", 3)];
+    /// map.dedup_keys(KeepPolicy::Last);
+    /// assert_eq!(map, [("b", 2), ("a", 3)]);
+    /// ```
+    fn dedup_keys(&mut self, policy: KeepPolicy);
+
+    /// Convert the map into a [`HashMap`]. If the vector contains duplicate keys, the first pair
+    /// with each key is kept, matching what [`AssocExt::get`] sees.
+    ///
+    /// Requires the `std` feature.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let map = vec![("a", 1), ("b", 2), ("a", 3)].into_hash_map();
+    /// assert_eq!(map.len(), 2);
+    /// assert_eq!(map["a"], 1);
+    /// ```
+    #[cfg(feature = "std")]
+    fn into_hash_map(self) -> HashMap<K, V>
+    where
+        K: Hash + Eq;
+
+    /// Convert the map into a [`BTreeMap`]. If the vector contains duplicate keys, the first pair
+    /// with each key is kept, matching what [`AssocExt::get`] sees.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let map = vec![("b", 1), ("a", 2), ("b", 3)].into_btree_map();
+    /// assert_eq!(map.into_iter().collect::<Vec<_>>(), [("a", 2), ("b", 1)]);
+    /// ```
+    fn into_btree_map(self) -> BTreeMap<K, V>
+    where
+        K: Ord;
+
+    /// Get an iterator over the key-value pairs of the map, in the order they appear in the
+    /// vector.
+    /// Items are yielded as `(&K, &V)` rather than as references to the underlying tuples, which
+    /// [`slice::iter`] still yields.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let map = vec![("a", 1), ("b", 2)];
+    /// let mut iter = map.pairs();
+    ///
+    /// assert_eq!(iter.next(), Some((&"a", &1)));
+    /// assert_eq!(iter.next(), Some((&"b", &2)));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    fn pairs(&self) -> Iter<'_, K, V>;
+
+    /// Get an iterator over the key-value pairs of the map, with mutable references to the values.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let mut map = vec![("a", 1), ("b", 2)];
+    ///
+    /// for (_, value) in map.pairs_mut() {
+    ///     *value *= 10;
+    /// }
+    ///
+    /// assert_eq!(map.get(&"a"), Some(&10));
+    /// assert_eq!(map.get(&"b"), Some(&20));
+    /// ```
+    fn pairs_mut(&mut self) -> IterMut<'_, K, V>;
+
+    /// Get an iterator over the keys of the map.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let map = vec![("a", 1), ("b", 2)];
+    /// let mut iter = map.keys();
+    ///
+    /// assert_eq!(iter.next().unwrap(), &"a");
+    /// assert_eq!(iter.next().unwrap(), &"b");
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    fn keys(&self) -> Keys<'_, K, V>;
+
+    /// Get an iterator over the distinct keys of the map, in order of first occurrence.
+    /// Keys that compare equal to an earlier key are skipped, so each key is yielded once even if
+    /// the vector contains duplicates.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let map = vec![("a", 1), ("b", 2), ("a", 3), ("c", 4), ("b", 5)];
+    /// let keys: Vec<&str> = map.unique_keys().copied().collect();
+    /// assert_eq!(keys, ["a", "b", "c"]);
+    /// ```
+    fn unique_keys(&self) -> UniqueKeys<'_, K, V>;
+
+    /// Create a consuming iterator visiting all the keys of the map.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let map = vec![(1, "a"), (2, "b")];
+    /// let keys: Vec<i32> = map.into_keys().collect();
+    /// assert_eq!(keys, [1, 2]);
+    /// ```
+    fn into_keys(self) -> IntoKeys<K, V, Self>;
+
+    /// Get an iterator over the values of the map.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let map = vec![("a", 1), ("b", 2)];
+    /// let mut iter = map.values();
+    ///
+    /// assert_eq!(iter.next().unwrap(), &1);
+    /// assert_eq!(iter.next().unwrap(), &2);
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    fn values(&self) -> Values<'_, K, V>;
+
+    /// Get a mutable iterator over the values of the map.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let mut map = vec![(1, "a".to_string()), (2, "b".to_string())];
+    ///
+    /// for value in map.values_mut() {
+    ///     value.push_str("!");
+    /// }
+    ///
+    /// let values: Vec<String> = map.values().cloned().collect();
+    /// assert_eq!(values, ["a!".to_string(), "b!".to_string()]);
+    /// ```
+    fn values_mut(&mut self) -> ValuesMut<'_, K, V>;
+
+    /// Create a consuming iterator visiting all the values of the map.
+    ///
+    /// ```rust
+    /// use assoc::AssocExt;
+    ///
+    /// let map = vec![(1, "a"), (2, "b")];
+    /// let values: Vec<&str> = map.into_values().collect();
+    /// assert_eq!(values, ["a", "b"]);
+    /// ```
+    fn into_values(self) -> IntoValues<K, V, Self>;
+}
+
+/// Implement [`AssocExt`] for a concrete storage type.
+///
+/// A blanket impl over `S: Storage<(K, V)>` would not know that `K` outlives the borrows of `S`,
+/// so each storage type gets its own impl with `K` and `V` appearing in the type.
+macro_rules! impl_assoc_ext {
+    ([$($generics:tt)*] $ty:ty) => {
+        impl<$($generics)*> AssocExt<K, V> for $ty
+        where
+            K: PartialEq,
+        {
+            fn entry(&mut self, key: K) -> Entry<'_, K, V, Self> {
+                let found = self.as_slice().iter().position(|(k, _)| k == &key);
+                match found {
+                    None => Entry::Vacant(VacantEntry::new(self, key)),
+                    Some(index) => Entry::Occupied(OccupiedEntry::new(self, index)),
+                }
+            }
+
+            fn get<Q>(&self, key: &Q) -> Option<&V>
+            where
+                Q: Equivalent<K> + ?Sized,
+            {
+                AssocSliceExt::get(self.as_slice(), key)
+            }
+
+            fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+            where
+                Q: Equivalent<K> + ?Sized,
+            {
+                self.as_mut_slice()
+                    .iter_mut()
+                    .find(|(k, _)| key.equivalent(k))
+                    .map(|(_, v)| v)
+            }
+
+            fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+            where
+                Q: Equivalent<K> + ?Sized,
+            {
+                AssocSliceExt::get_key_value(self.as_slice(), key)
+            }
+
+            fn contains_key<Q>(&self, key: &Q) -> bool
+            where
+                Q: Equivalent<K> + ?Sized,
+            {
+                AssocSliceExt::contains_key(self.as_slice(), key)
+            }
+
+            fn insert(&mut self, key: K, value: V) -> Option<V> {
+                match self.entry(key) {
+                    Entry::Occupied(mut entry) => Some(entry.insert(value)),
+                    Entry::Vacant(entry) => {
+                        entry.insert(value);
+                        None
+                    }
+                }
+            }
+
+            fn remove<Q>(&mut self, key: &Q) -> Option<V>
+            where
+                Q: Equivalent<K> + ?Sized,
+            {
+                AssocExt::remove_entry(self, key).map(|(_, v)| v)
+            }
+
+            fn remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
+            where
+                Q: Equivalent<K> + ?Sized,
+            {
+                let index = AssocExt::index_of(self, key)?;
+                Some(Storage::swap_remove(self, index))
+            }
+
+            fn shift_remove<Q>(&mut self, key: &Q) -> Option<V>
+            where
+                Q: Equivalent<K> + ?Sized,
+            {
+                AssocExt::shift_remove_entry(self, key).map(|(_, v)| v)
+            }
+
+            fn shift_remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
+            where
+                Q: Equivalent<K> + ?Sized,
+            {
+                let index = AssocExt::index_of(self, key)?;
+                Some(Storage::remove(self, index))
+            }
+
+            fn retain<F>(&mut self, mut f: F)
+            where
+                F: FnMut(&K, &mut V) -> bool,
+            {
+                Storage::retain_mut(self, |(k, v)| f(k, v));
+            }
+
+            fn drain(&mut self) -> Drain<'_, K, V, Self> {
+                Drain {
+                    inner: Storage::drain(self),
+                }
+            }
+
+            fn extract_if<F>(&mut self, pred: F) -> ExtractIf<'_, K, V, F, Self>
+            where
+                F: FnMut(&K, &mut V) -> bool,
+            {
+                ExtractIf::new(self, pred)
+            }
+
+            fn get_by<P>(&self, mut pred: P) -> Option<&V>
+            where
+                P: FnMut(&K) -> bool,
+            {
+                self.as_slice()
+                    .iter()
+                    .find(|(k, _)| pred(k))
+                    .map(|(_, v)| v)
+            }
+
+            fn get_mut_by<P>(&mut self, mut pred: P) -> Option<&mut V>
+            where
+                P: FnMut(&K) -> bool,
+            {
+                self.as_mut_slice()
+                    .iter_mut()
+                    .find(|(k, _)| pred(k))
+                    .map(|(_, v)| v)
+            }
+
+            fn entry_by<P, F>(&mut self, mut pred: P, make_key: F) -> Entry<'_, K, V, Self>
+            where
+                P: FnMut(&K) -> bool,
+                F: FnOnce() -> K,
+            {
+                let found = self.as_slice().iter().position(|(k, _)| pred(k));
+                match found {
+                    None => Entry::Vacant(VacantEntry::new(self, make_key())),
+                    Some(index) => Entry::Occupied(OccupiedEntry::new(self, index)),
+                }
+            }
+
+            fn entry_ref<'a, 'b, Q>(&'a mut self, key: &'b Q) -> EntryRef<'a, 'b, K, Q, V, Self>
+            where
+                Q: Equivalent<K> + ToOwned<Owned = K> + ?Sized,
+            {
+                let found = self.as_slice().iter().position(|(k, _)| key.equivalent(k));
+                match found {
+                    None => EntryRef::Vacant(VacantEntryRef::new(self, key)),
+                    Some(index) => EntryRef::Occupied(OccupiedEntry::new(self, index)),
+                }
+            }
+
+            fn first_key_value(&self) -> Option<(&K, &V)> {
+                self.as_slice().first().map(|(k, v)| (k, v))
+            }
+
+            fn last_key_value(&self) -> Option<(&K, &V)> {
+                self.as_slice().last().map(|(k, v)| (k, v))
+            }
+
+            fn pop_first(&mut self) -> Option<(K, V)> {
+                if self.as_slice().is_empty() {
+                    None
+                } else {
+                    Some(Storage::remove(self, 0))
+                }
+            }
+
+            fn pop_last(&mut self) -> Option<(K, V)> {
+                Storage::pop(self)
+            }
+
+            fn first_entry(&mut self) -> Option<OccupiedEntry<'_, K, V, Self>> {
+                if self.as_slice().is_empty() {
+                    None
+                } else {
+                    Some(OccupiedEntry::new(self, 0))
+                }
+            }
+
+            fn last_entry(&mut self) -> Option<OccupiedEntry<'_, K, V, Self>> {
+                let index = self.as_slice().len().checked_sub(1)?;
+                Some(OccupiedEntry::new(self, index))
+            }
+
+            fn get_index(&self, index: usize) -> Option<(&K, &V)> {
+                self.as_slice().get(index).map(|(k, v)| (k, v))
+            }
+
+            fn get_index_mut(&mut self, index: usize) -> Option<(&K, &mut V)> {
+                self.as_mut_slice().get_mut(index).map(|(k, v)| (&*k, v))
+            }
+
+            fn index_of<Q>(&self, key: &Q) -> Option<usize>
+            where
+                Q: Equivalent<K> + ?Sized,
+            {
+                self.as_slice().iter().position(|(k, _)| key.equivalent(k))
+            }
+
+            fn swap_indices(&mut self, a: usize, b: usize) {
+                self.as_mut_slice().swap(a, b);
+            }
+
+            fn try_insert(&mut self, key: K, value: V) -> Result<&mut V, OccupiedError<'_, K, V, Self>> {
+                match self.entry(key) {
+                    Entry::Occupied(entry) => Err(OccupiedError { entry, value }),
+                    Entry::Vacant(entry) => Ok(entry.insert(value)),
+                }
+            }
+
+            fn insert_with<F>(&mut self, key: K, value: V, resolve: F) -> &mut V
+            where
+                F: FnOnce(&mut V, V),
+            {
+                match self.entry(key) {
+                    Entry::Occupied(entry) => {
+                        let old = entry.into_mut();
+                        resolve(old, value);
+                        old
+                    }
+                    Entry::Vacant(entry) => entry.insert(value),
+                }
+            }
+
+            fn merge<I, F>(&mut self, other: I, mut resolve: F)
+            where
+                I: IntoIterator<Item = (K, V)>,
+                F: FnMut(&mut V, V),
+            {
+                for (k, v) in other {
+                    self.insert_with(k, v, &mut resolve);
+                }
+            }
+
+            fn extend_replace<I>(&mut self, iter: I) -> usize
+            where
+                I: IntoIterator<Item = (K, V)>,
+            {
+                let iter = iter.into_iter();
+                self.reserve(iter.size_hint().0);
+                let mut replaced = 0;
+                for (k, v) in iter {
+                    if AssocExt::insert(self, k, v).is_some() {
+                        replaced += 1;
+                    }
+                }
+                replaced
+            }
+
+            fn extend_keep<I>(&mut self, iter: I) -> usize
+            where
+                I: IntoIterator<Item = (K, V)>,
+            {
+                let iter = iter.into_iter();
+                self.reserve(iter.size_hint().0);
+                let mut skipped = 0;
+                for (k, v) in iter {
+                    match self.entry(k) {
+                        Entry::Occupied(_) => skipped += 1,
+                        Entry::Vacant(entry) => {
+                            entry.insert(v);
+                        }
+                    }
+                }
+                skipped
+            }
+
+            fn difference<'a>(&'a self, other: &'a [(K, V)]) -> Difference<'a, K, V> {
+                Difference {
+                    inner: self.as_slice().iter(),
+                    other,
+                }
+            }
+
+            fn intersection<'a>(&'a self, other: &'a [(K, V)]) -> Intersection<'a, K, V> {
+                Intersection {
+                    inner: self.as_slice().iter(),
+                    other,
+                }
+            }
+
+            fn union<'a>(&'a self, other: &'a [(K, V)]) -> Union<'a, K, V> {
+                let rest = Difference {
+                    inner: other.iter(),
+                    other: self,
+                };
+                Union {
+                    inner: AssocExt::pairs(self).chain(rest),
+                }
+            }
+
+            fn symmetric_difference<'a>(&'a self, other: &'a [(K, V)]) -> SymmetricDifference<'a, K, V> {
+                let rest = Difference {
+                    inner: other.iter(),
+                    other: self,
+                };
+                SymmetricDifference {
+                    inner: AssocExt::difference(self, other).chain(rest),
+                }
+            }
+
+            fn diff<'a>(&'a self, other: &'a [(K, V)]) -> Diff<'a, K, V> {
+                Diff {
+                    old: self.as_slice(),
+                    new: other,
+                }
+            }
+
+            fn get_disjoint_mut<Q, const N: usize>(&mut self, keys: [&Q; N]) -> [Option<&mut V>; N]
+            where
+                Q: Equivalent<K> + ?Sized,
+            {
+                let indices = keys.map(|key| AssocExt::index_of(self, key));
+                for (i, index) in indices.iter().enumerate() {
+                    if index.is_some() && indices[..i].contains(index) {
+                        panic!("get_disjoint_mut: overlapping keys");
+                    }
+                }
+
+                let mut values: [Option<&mut V>; N] = core::array::from_fn(|_| None);
+                for (i, (_, v)) in self.as_mut_slice().iter_mut().enumerate() {
+                    if let Some(slot) = indices.iter().position(|&index| index == Some(i)) {
+                        values[slot] = Some(v);
+                    }
+                }
+                values
+            }
+
+            fn get_promote<Q>(&mut self, key: &Q) -> Option<&mut V>
+            where
+                Q: Equivalent<K> + ?Sized,
+            {
+                let index = AssocExt::index_of(self, key)?;
+                let pairs = &mut self.as_mut_slice()[..=index];
+                pairs.rotate_right(1);
+                let (_, v) = &mut pairs[0];
+                Some(v)
+            }
+
+            fn get_touch<Q>(&mut self, key: &Q) -> Option<&mut V>
+            where
+                Q: Equivalent<K> + ?Sized,
+            {
+                let index = AssocExt::index_of(self, key)?;
+                let pairs = &mut self.as_mut_slice()[index..];
+                pairs.rotate_left(1);
+                let (_, v) = pairs.last_mut()?;
+                Some(v)
+            }
+
+            fn insert_bounded(
+                &mut self,
+                key: K,
+                value: V,
+                max_len: usize,
+                policy: EvictPolicy,
+            ) -> Option<(K, V)> {
+                debug_assert!(max_len > 0, "insert_bounded: max_len must be positive");
+                match self.as_slice().iter().position(|(k, _)| *k == key) {
+                    Some(index) => {
+                        let old = mem::replace(&mut self.as_mut_slice()[index].1, value);
+                        if policy == EvictPolicy::LeastRecentlyUsed {
+                            self.as_mut_slice()[index..].rotate_left(1);
+                        }
+                        Some((key, old))
+                    }
+                    None if max_len == 0 => Some((key, value)),
+                    None => {
+                        let len = self.as_slice().len();
+                        let mut evicted = None;
+                        if len >= max_len {
+                            // Move the pairs to evict to the back in one pass, then pop them off.
+                            let excess = len - max_len + 1;
+                            self.as_mut_slice().rotate_left(excess);
+                            evicted = Storage::pop(self);
+                            for _ in 1..excess {
+                                Storage::pop(self);
+                            }
+                        }
+                        let len = self.as_slice().len();
+                        Storage::insert(self, len, (key, value));
+                        evicted
+                    }
+                }
+            }
+
+            fn cursor_mut(&mut self) -> CursorMut<'_, K, V, Self> {
+                CursorMut::new(self)
+            }
+
+            fn has_duplicate_keys(&self) -> bool {
+                AssocExt::duplicate_keys(self).next().is_some()
+            }
+
+            fn duplicate_keys(&self) -> DuplicateKeys<'_, K, V> {
+                DuplicateKeys {
+                    vec: self.as_slice(),
+                    index: 0,
+                }
+            }
+
+            fn dedup_keys(&mut self, policy: KeepPolicy) {
+                let mut index = 0;
+                while index < self.as_slice().len() {
+                    let (before, after) = self.as_slice().split_at(index);
+                    let (key, _) = &after[0];
+                    let duplicate = match policy {
+                        KeepPolicy::First => before.iter().any(|(k, _)| k == key),
+                        KeepPolicy::Last => after[1..].iter().any(|(k, _)| k == key),
+                    };
+                    if duplicate {
+                        Storage::remove(self, index);
+                    } else {
+                        index += 1;
+                    }
+                }
+            }
+
+            #[cfg(feature = "std")]
+            fn into_hash_map(self) -> HashMap<K, V>
+            where
+                K: Hash + Eq,
+            {
+                let mut map = HashMap::with_capacity(self.as_slice().len());
+                for (k, v) in self {
+                    map.entry(k).or_insert(v);
+                }
+                map
+            }
+
+            fn into_btree_map(self) -> BTreeMap<K, V>
+            where
+                K: Ord,
+            {
+                let mut map = BTreeMap::new();
+                for (k, v) in self {
+                    map.entry(k).or_insert(v);
+                }
+                map
+            }
+
+            fn pairs(&self) -> Iter<'_, K, V> {
+                Iter {
+                    inner: self.as_slice().iter(),
+                }
+            }
+
+            fn pairs_mut(&mut self) -> IterMut<'_, K, V> {
+                IterMut {
+                    inner: self.as_mut_slice().iter_mut(),
+                }
+            }
+
+            fn keys(&self) -> Keys<'_, K, V> {
+                AssocSliceExt::keys(self.as_slice())
+            }
+
+            fn unique_keys(&self) -> UniqueKeys<'_, K, V> {
+                UniqueKeys {
+                    vec: self.as_slice(),
+                    index: 0,
+                }
+            }
+
+            fn into_keys(self) -> IntoKeys<K, V, Self> {
+                IntoKeys {
+                    inner: self.into_iter(),
+                }
+            }
+
+            fn values(&self) -> Values<'_, K, V> {
+                AssocSliceExt::values(self.as_slice())
+            }
+
+            fn values_mut(&mut self) -> ValuesMut<'_, K, V> {
+                ValuesMut {
+                    inner: self.as_mut_slice().iter_mut(),
+                }
+            }
+
+            fn into_values(self) -> IntoValues<K, V, Self> {
+                IntoValues {
+                    inner: self.into_iter(),
+                }
+            }
+        }
+    };
+}
+
+impl_assoc_ext!([K, V] Vec<(K, V)>);
+#[cfg(feature = "smallvec")]
+impl_assoc_ext!([K, V, const CAP: usize] smallvec::SmallVec<[(K, V); CAP]>);
+#[cfg(feature = "arrayvec")]
+impl_assoc_ext!([K, V, const CAP: usize] arrayvec::ArrayVec<(K, V), CAP>);
+
+/// Panic if the map has duplicate keys, when the `debug-unique-keys` feature is enabled and debug
+/// assertions are on.
+fn debug_assert_unique_keys<K, V, S>(map: &S)
+where
+    K: PartialEq,
+    S: AssocExt<K, V>,
+{
+    if cfg!(feature = "debug-unique-keys") {
+        debug_assert!(
+            !map.has_duplicate_keys(),
+            "map used through AssocStrictExt has duplicate keys",
+        );
+    }
+}
+
+/// This has the same API as [`AssocExt`] but with the additional constraint `K: Eq`.
+///
+/// With the `debug-unique-keys` feature enabled, the methods that look up a key check that the
+/// map has no duplicate keys and panic otherwise. The check is quadratic in the length of the map
+/// and only runs when debug assertions are enabled.
+///
+/// ```compile_fail
+/// use assoc::AssocStrictExt;
+///
+/// let map = vec![(1.0, 1), (2.0, 2)];
+/// map.entry(1.0);
+/// ```
+pub trait AssocStrictExt<K, V>: Storage<(K, V)> {
+    /// Get a key's entry for in-place manipulation.
+    ///
+    /// ```rust
+    /// use assoc::AssocStrictExt;
+    ///
+    /// let mut count = Vec::new();
+    /// for x in vec!["a", "b", "c", "b"] {
+    ///     *count.entry(x).or_insert(0) += 1;
+    /// }
+    /// assert_eq!(count.get(&"b"), Some(&2));
+    /// ```
+    fn entry(&mut self, key: K) -> Entry<'_, K, V, Self>;
+
+    /// Get a reference to the value associated with a key.
+    ///
+    /// ```rust
+    /// use assoc::AssocStrictExt;
+    ///
+    /// let map = vec![("a", 1), ("b", 2)];
+    /// assert_eq!(map.get(&"a"), Some(&1));
+    /// ```
+    fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        Q: Equivalent<K> + ?Sized;
+
+    /// Get a mutable reference to the value associated with a key.
+    ///
+    /// ```rust
+    /// use assoc::AssocStrictExt;
+    ///
+    /// let mut map = vec![("a", 1), ("b", 2)];
+    /// *map.get_mut(&"a").unwrap() += 1;
+    /// assert_eq!(map.get(&"a"), Some(&2));
+    /// ```
+    fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        Q: Equivalent<K> + ?Sized;
+
+    /// Get the key-value pair associated with a key.
+    ///
+    /// ```rust
+    /// use assoc::AssocStrictExt;
+    ///
+    /// let map = vec![("a", 1), ("b", 2)];
+    /// assert_eq!(map.get_key_value(&"a"), Some((&"a", &1)));
+    /// ```
+    fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        Q: Equivalent<K> + ?Sized;
+
+    /// Check whether the map contains a value for a key.
+    ///
+    /// ```rust
+    /// use assoc::AssocStrictExt;
+    ///
+    /// let map = vec![("a", 1), ("b", 2)];
+    /// assert!(map.contains_key(&"a"));
+    /// ```
+    fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        Q: Equivalent<K> + ?Sized;
+
+    /// Insert a key-value pair into the associative array.
+    /// If the map previously had the key, then the old value is returned. Otherwise, `None` is
+    /// returned.
+    ///
+    /// ```rust
+    /// use assoc::AssocStrictExt;
+    ///
+    /// let mut map = vec![("b", 3)];
+    /// assert_eq!(AssocStrictExt::insert(&mut map, "a", 1), None);
+    /// assert_eq!(AssocStrictExt::insert(&mut map, "a", 2), Some(1));
+    /// ```
+    fn insert(&mut self, key: K, value: V) -> Option<V>;
+
+    /// Remove a key from the map, returning the value if it was previously in the map.
+    ///
+    /// ```rust
+    /// use assoc::AssocStrictExt;
+    ///
+    /// let mut map = vec![("a", 1)];
+    /// assert_eq!(AssocStrictExt::remove(&mut map, "a"), Some(1));
+    /// assert_eq!(AssocStrictExt::remove(&mut map, "a"), None);
+    /// ```
+    fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        Q: Equivalent<K> + ?Sized;
+
+    /// Remove a key from the map, returning the stored key and value if the key was previously in
+    /// the map.
+    ///
+    /// ```rust
+    /// use assoc::AssocStrictExt;
+    ///
+    /// let mut map = vec![("a", 1)];
+    /// assert_eq!(map.remove_entry(&"a"), Some(("a", 1)));
+    /// assert_eq!(map.remove_entry(&"a"), None);
+    /// ```
+    fn remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        Q: Equivalent<K> + ?Sized;
+
+    /// Remove a key from the map, preserving the order of the remaining pairs, and return the
+    /// value if it was previously in the map.
+    ///
+    /// ```rust
+    /// use assoc::AssocStrictExt;
+    ///
+    /// let mut map = vec![("a", 1), ("b", 2), ("c", 3)];
+    /// assert_eq!(map.shift_remove(&"a"), Some(1));
+    /// assert_eq!(map, [("b", 2), ("c", 3)]);
+    /// ```
+    fn shift_remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        Q: Equivalent<K> + ?Sized;
+
+    /// Remove a key from the map, preserving the order of the remaining pairs, and return the
+    /// stored key and value if the key was previously in the map.
+    ///
+    /// ```rust
+    /// use assoc::AssocStrictExt;
+    ///
+    /// let mut map = vec![("a", 1), ("b", 2), ("c", 3)];
+    /// assert_eq!(map.shift_remove_entry(&"a"), Some(("a", 1)));
+    /// assert_eq!(map, [("b", 2), ("c", 3)]);
+    /// ```
+    fn shift_remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        Q: Equivalent<K> + ?Sized;
+
+    /// Retain only the pairs specified by the predicate, preserving their relative order.
+    ///
+    /// ```rust
+    /// use assoc::AssocStrictExt;
+    ///
+    /// let mut map = vec![("a", 1), ("b", 2), ("c", 3), ("d", 4)];
+    /// AssocStrictExt::retain(&mut map, |_, v| *v % 2 == 0);
+    /// assert_eq!(map, [("b", 2), ("d", 4)]);
+    /// ```
+    fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&K, &mut V) -> bool;
+
+    /// Remove all pairs from the map, returning them as an iterator in order.
+    ///
+    /// ```rust
+    /// use assoc::AssocStrictExt;
+    ///
+    /// let mut map = vec![("a", 1), ("b", 2)];
+    /// let drained: Vec<(&str, i32)> = AssocStrictExt::drain(&mut map).collect();
+    /// assert_eq!(drained, [("a", 1), ("b", 2)]);
+    /// assert!(map.is_empty());
+    /// ```
+    fn drain(&mut self) -> Drain<'_, K, V, Self>;
+
+    /// Create an iterator that removes and yields the pairs for which `pred(&k, &mut v)` returns
+    /// `true`.
+    ///
+    /// ```rust
+    /// use assoc::AssocStrictExt;
+    ///
+    /// let mut map = vec![("a", 1), ("b", 2), ("c", 3)];
+    /// let odds: Vec<(&str, i32)> = AssocStrictExt::extract_if(&mut map, |_, v| *v % 2 == 1).collect();
+    /// assert_eq!(odds, [("a", 1), ("c", 3)]);
+    /// assert_eq!(map, [("b", 2)]);
+    /// ```
+    fn extract_if<F>(&mut self, pred: F) -> ExtractIf<'_, K, V, F, Self>
+    where
+        F: FnMut(&K, &mut V) -> bool;
+
+    /// Get a reference to the value of the first pair whose key satisfies a predicate.
+    ///
+    /// ```rust
+    /// use assoc::AssocStrictExt;
+    ///
+    /// let map = vec![("apple", 1), ("banana", 2)];
+    /// assert_eq!(map.get_by(|k| k.starts_with('b')), Some(&2));
+    /// ```
+    fn get_by<P>(&self, pred: P) -> Option<&V>
+    where
+        P: FnMut(&K) -> bool;
+
+    /// Get a mutable reference to the value of the first pair whose key satisfies a predicate.
+    ///
+    /// ```rust
+    /// use assoc::AssocStrictExt;
+    ///
+    /// let mut map = vec![("apple", 1), ("banana", 2)];
+    /// *map.get_mut_by(|k| k.starts_with('a')).unwrap() += 10;
+    /// assert_eq!(map.get(&"apple"), Some(&11));
+    /// ```
+    fn get_mut_by<P>(&mut self, pred: P) -> Option<&mut V>
+    where
+        P: FnMut(&K) -> bool;
+
+    /// Get the entry of the first pair whose key satisfies a predicate, calling `make_key` to
+    /// create the key only if the entry is vacant.
+    ///
+    /// ```rust
+    /// use assoc::AssocStrictExt;
+    ///
+    /// let mut map = vec![("apple".to_string(), 1)];
+    /// *map.entry_by(|k| k == "apple", || "apple".to_string()).or_insert(0) += 1;
+    /// assert_eq!(map, [("apple".to_string(), 2)]);
+    /// ```
+    fn entry_by<P, F>(&mut self, pred: P, make_key: F) -> Entry<'_, K, V, Self>
+    where
+        P: FnMut(&K) -> bool,
+        F: FnOnce() -> K;
+
+    /// Get a key's entry for in-place manipulation, looking it up by a borrowed key that is only
+    /// converted into an owned key if a value is inserted.
+    ///
+    /// ```rust
+    /// use assoc::AssocStrictExt;
+    ///
+    /// let mut count: Vec<(String, u32)> = Vec::new();
+    /// for word in "a b a".split(' ') {
+    ///     *count.entry_ref(word).or_insert(0) += 1;
+    /// }
+    /// assert_eq!(count.get("a"), Some(&2));
+    /// ```
+    fn entry_ref<'a, 'b, Q>(&'a mut self, key: &'b Q) -> EntryRef<'a, 'b, K, Q, V, Self>
+    where
+        Q: Equivalent<K> + ToOwned<Owned = K> + ?Sized;
+
+    /// Get the first key-value pair in the map.
+    ///
+    /// ```rust
+    /// use assoc::AssocStrictExt;
+    ///
+    /// let map = vec![("a", 1), ("b", 2)];
+    /// assert_eq!(map.first_key_value(), Some((&"a", &1)));
+    /// ```
+    fn first_key_value(&self) -> Option<(&K, &V)>;
+
+    /// Get the last key-value pair in the map.
+    ///
+    /// ```rust
+    /// use assoc::AssocStrictExt;
+    ///
+    /// let map = vec![("a", 1), ("b", 2)];
+    /// assert_eq!(map.last_key_value(), Some((&"b", &2)));
+    /// ```
+    fn last_key_value(&self) -> Option<(&K, &V)>;
+
+    /// Remove and return the first key-value pair in the map, preserving the order of the rest.
+    ///
+    /// ```rust
+    /// use assoc::AssocStrictExt;
+    ///
+    /// let mut map = vec![("a", 1), ("b", 2), ("c", 3)];
+    /// assert_eq!(map.pop_first(), Some(("a", 1)));
+    /// assert_eq!(map, [("b", 2), ("c", 3)]);
+    /// ```
+    fn pop_first(&mut self) -> Option<(K, V)>;
+
+    /// Remove and return the last key-value pair in the map.
+    ///
+    /// ```rust
+    /// use assoc::AssocStrictExt;
+    ///
+    /// let mut map = vec![("a", 1), ("b", 2)];
+    /// assert_eq!(map.pop_last(), Some(("b", 2)));
+    /// ```
+    fn pop_last(&mut self) -> Option<(K, V)>;
+
+    /// Get the entry of the first pair in the map for in-place manipulation.
+    ///
+    /// ```rust
+    /// use assoc::AssocStrictExt;
+    ///
+    /// let mut map = vec![("a", 1), ("b", 2)];
+    /// assert_eq!(map.first_entry().unwrap().key(), &"a");
+    /// ```
+    fn first_entry(&mut self) -> Option<OccupiedEntry<'_, K, V, Self>>;
+
+    /// Get the entry of the last pair in the map for in-place manipulation.
+    ///
+    /// ```rust
+    /// use assoc::AssocStrictExt;
+    ///
+    /// let mut map = vec![("a", 1), ("b", 2)];
+    /// assert_eq!(map.last_entry().unwrap().key(), &"b");
+    /// ```
+    fn last_entry(&mut self) -> Option<OccupiedEntry<'_, K, V, Self>>;
+
+    /// Get the key-value pair at a position in the vector.
+    ///
+    /// ```rust
+    /// use assoc::AssocStrictExt;
+    ///
+    /// let map = vec![("a", 1), ("b", 2)];
+    /// assert_eq!(map.get_index(1), Some((&"b", &2)));
+    /// ```
+    fn get_index(&self, index: usize) -> Option<(&K, &V)>;
+
+    /// Get the key and a mutable reference to the value at a position in the vector.
+    ///
+    /// ```rust
+    /// use assoc::AssocStrictExt;
+    ///
+    /// let mut map = vec![("a", 1), ("b", 2)];
+    /// *map.get_index_mut(0).unwrap().1 += 10;
+    /// assert_eq!(map, [("a", 11), ("b", 2)]);
+    /// ```
+    fn get_index_mut(&mut self, index: usize) -> Option<(&K, &mut V)>;
+
+    /// Get the position of a key in the vector.
+    ///
+    /// ```rust
+    /// use assoc::AssocStrictExt;
+    ///
+    /// let map = vec![("a", 1), ("b", 2)];
+    /// assert_eq!(map.index_of(&"b"), Some(1));
+    /// ```
+    fn index_of<Q>(&self, key: &Q) -> Option<usize>
+    where
+        Q: Equivalent<K> + ?Sized;
+
+    /// Swap the positions of two key-value pairs.
+    ///
+    /// ```rust
+    /// use assoc::AssocStrictExt;
+    ///
+    /// let mut map = vec![("a", 1), ("b", 2)];
+    /// map.swap_indices(0, 1);
+    /// assert_eq!(map, [("b", 2), ("a", 1)]);
+    /// ```
+    fn swap_indices(&mut self, a: usize, b: usize);
+
+    /// Insert a key-value pair into the map if the key is not already present, and return a mutable
+    /// reference to the inserted value.
+    /// If the key is already present, an error containing the occupied entry and the rejected value
+    /// is returned.
+    ///
+    /// ```rust
+    /// use assoc::AssocStrictExt;
+    ///
+    /// let mut map = vec![("a", 1)];
+    /// assert_eq!(map.try_insert("b", 2).unwrap(), &2);
+    /// assert_eq!(map.try_insert("a", 3).unwrap_err().value, 3);
+    /// ```
+    fn try_insert(&mut self, key: K, value: V) -> Result<&mut V, OccupiedError<'_, K, V, Self>>;
+
+    /// Insert a key-value pair into the map, using `resolve` to combine the values if the key is
+    /// already present.
+    ///
+    /// ```rust
+    /// use assoc::AssocStrictExt;
+    ///
+    /// let mut counts = vec![("a", 1)];
+    /// counts.insert_with("a", 5, |old, new| *old += new);
+    /// assert_eq!(counts, [("a", 6)]);
+    /// ```
+    fn insert_with<F>(&mut self, key: K, value: V, resolve: F) -> &mut V
+    where
+        F: FnOnce(&mut V, V);
+
+    /// Insert all the pairs from `other` into the map, using `resolve` to combine the values of
+    /// keys that are already present.
+    ///
+    /// ```rust
+    /// use assoc::AssocStrictExt;
+    ///
+    /// let mut shard = vec![("a", 1), ("b", 2)];
+    /// shard.merge(vec![("b", 3), ("c", 4)], |old, new| *old += new);
+    /// assert_eq!(shard, [("a", 1), ("b", 5), ("c", 4)]);
+    /// ```
+    fn merge<I, F>(&mut self, other: I, resolve: F)
+    where
+        I: IntoIterator<Item = (K, V)>,
+        F: FnMut(&mut V, V);
+
+    /// Insert the key-value pairs of an iterator into the map, replacing the values of keys that
+    /// are already present. Returns the number of values that were replaced.
+    ///
+    /// ```rust
+    /// use assoc::AssocStrictExt;
+    ///
+    /// let mut map = vec![("a", 1)];
+    /// assert_eq!(map.extend_replace(vec![("a", 10), ("b", 2)]), 1);
+    /// assert_eq!(map, [("a", 10), ("b", 2)]);
+    /// ```
+    fn extend_replace<I>(&mut self, iter: I) -> usize
+    where
+        I: IntoIterator<Item = (K, V)>;
+
+    /// Insert the key-value pairs of an iterator into the map, skipping keys that are already
+    /// present. Returns the number of pairs that were skipped.
+    ///
+    /// ```rust
+    /// use assoc::AssocStrictExt;
+    ///
+    /// let mut map = vec![("a", 1)];
+    /// assert_eq!(map.extend_keep(vec![("a", 10), ("b", 2)]), 1);
+    /// assert_eq!(map, [("a", 1), ("b", 2)]);
+    /// ```
+    fn extend_keep<I>(&mut self, iter: I) -> usize
+    where
+        I: IntoIterator<Item = (K, V)>;
+
+    /// Get an iterator over the key-value pairs of the map whose keys are not in `other`.
+    ///
+    /// ```rust
+    /// use assoc::AssocStrictExt;
+    ///
+    /// let a = vec![("a", 1), ("b", 2)];
+    /// assert_eq!(a.difference(&[("b", 3)]).collect::<Vec<_>>(), [(&"a", &1)]);
+    /// ```
+    fn difference<'a>(&'a self, other: &'a [(K, V)]) -> Difference<'a, K, V>;
+
+    /// Get an iterator over the key-value pairs of the map whose keys are also in `other`.
+    ///
+    /// ```rust
+    /// use assoc::AssocStrictExt;
+    ///
+    /// let a = vec![("a", 1), ("b", 2)];
+    /// assert_eq!(a.intersection(&[("b", 3)]).collect::<Vec<_>>(), [(&"b", &2)]);
+    /// ```
+    fn intersection<'a>(&'a self, other: &'a [(K, V)]) -> Intersection<'a, K, V>;
+
+    /// Get an iterator over the key-value pairs of the map, followed by the key-value pairs of
+    /// `other` whose keys are not in this map.
+    ///
+    /// ```rust
+    /// use assoc::AssocStrictExt;
+    ///
+    /// let a = vec![("a", 1)];
+    /// assert_eq!(a.union(&[("a", 2), ("b", 3)]).collect::<Vec<_>>(), [(&"a", &1), (&"b", &3)]);
+    /// ```
+    fn union<'a>(&'a self, other: &'a [(K, V)]) -> Union<'a, K, V>;
+
+    /// Get an iterator over the key-value pairs whose keys are in exactly one of the two maps.
+    ///
+    /// ```rust
+    /// use assoc::AssocStrictExt;
+    ///
+    /// let a = vec![("a", 1), ("b", 2)];
+    /// let b = [("b", 3), ("c", 4)];
+    /// assert_eq!(a.symmetric_difference(&b).collect::<Vec<_>>(), [(&"a", &1), (&"c", &4)]);
+    /// ```
+    fn symmetric_difference<'a>(&'a self, other: &'a [(K, V)]) -> SymmetricDifference<'a, K, V>;
+
+    /// Compare the map with a newer version of it, `other`.
+    ///
+    /// ```rust
+    /// use assoc::AssocStrictExt;
+    ///
+    /// let a = vec![("a", 1), ("b", 2)];
+    /// let diff = a.diff(&[("a", 1), ("b", 3)]);
+    /// assert_eq!(diff.changed().collect::<Vec<_>>(), [(&"b", &2, &3)]);
+    /// ```
+    fn diff<'a>(&'a self, other: &'a [(K, V)]) -> Diff<'a, K, V>;
+
+    /// Get mutable references to the values of several keys at once.
+    ///
+    /// ```rust
+    /// use assoc::AssocStrictExt;
+    ///
+    /// let mut map = vec![("a", 1), ("b", 2)];
+    /// let [a, b] = map.get_disjoint_mut([&"a", &"b"]);
+    /// std::mem::swap(a.unwrap(), b.unwrap());
+    /// assert_eq!(map, [("a", 2), ("b", 1)]);
+    /// ```
+    fn get_disjoint_mut<Q, const N: usize>(&mut self, keys: [&Q; N]) -> [Option<&mut V>; N]
+    where
+        Q: Equivalent<K> + ?Sized;
+
+    /// Get a mutable reference to the value corresponding to the key, and move the pair to the
+    /// front of the map.
+    ///
+    /// ```rust
+    /// use assoc::AssocStrictExt;
+    ///
+    /// let mut map = vec![("a", 1), ("b", 2)];
+    /// assert_eq!(map.get_promote(&"b"), Some(&mut 2));
+    /// assert_eq!(map, [("b", 2), ("a", 1)]);
+    /// ```
+    fn get_promote<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        Q: Equivalent<K> + ?Sized;
+
+    /// Get a mutable reference to the value corresponding to the key, and move the pair to the
+    /// back of the map.
+    ///
+    /// ```rust
+    /// use assoc::AssocStrictExt;
+    ///
+    /// let mut map = vec![("a", 1), ("b", 2)];
+    /// assert_eq!(map.get_touch(&"a"), Some(&mut 1));
+    /// assert_eq!(map, [("b", 2), ("a", 1)]);
+    /// ```
+    fn get_touch<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        Q: Equivalent<K> + ?Sized;
+
+    /// Insert a key-value pair into a map that should hold at most `max_len` pairs, evicting
+    /// pairs from the front according to `policy` if the map is full.
     ///
     /// ```rust
-    /// use assoc::AssocExt;
+    /// use assoc::vec::EvictPolicy;
+    /// use assoc::AssocStrictExt;
     ///
-    /// let mut map = vec![("a", 1)];
-    /// assert_eq!(AssocExt::remove(&mut map, "a"), Some(1));
-    /// assert_eq!(AssocExt::remove(&mut map, "a"), None);
+    /// let mut cache = vec![("a", 1), ("b", 2)];
+    /// assert_eq!(cache.insert_bounded("c", 3, 2, EvictPolicy::Oldest), Some(("a", 1)));
+    /// assert_eq!(cache, [("b", 2), ("c", 3)]);
     /// ```
-    fn remove<Q>(&mut self, key: &Q) -> Option<V>
-    where
-        K: Borrow<Q>,
-        Q: PartialEq + ?Sized;
+    fn insert_bounded(
+        &mut self,
+        key: K,
+        value: V,
+        max_len: usize,
+        policy: EvictPolicy,
+    ) -> Option<(K, V)>;
 
-    /// Get an iterator over the keys of the map.
+    /// Get a cursor pointing at the first pair of the map, for editing the map in a single pass.
     ///
     /// ```rust
-    /// use assoc::AssocExt;
-    ///
-    /// let map = vec![("a", 1), ("b", 2)];
-    /// let mut iter = map.keys();
+    /// use assoc::AssocStrictExt;
     ///
-    /// assert_eq!(iter.next().unwrap(), &"a");
-    /// assert_eq!(iter.next().unwrap(), &"b");
-    /// assert_eq!(iter.next(), None);
+    /// let mut map = vec![("a", 1), ("b", 2)];
+    /// assert_eq!(map.cursor_mut().remove_current(), Some(("a", 1)));
+    /// assert_eq!(map, [("b", 2)]);
     /// ```
-    fn keys(&self) -> Keys<'_, K, V>;
+    fn cursor_mut(&mut self) -> CursorMut<'_, K, V, Self>;
 
-    /// Create a consuming iterator visiting all the keys of the map.
+    /// Check whether any key occurs more than once in the vector.
     ///
     /// ```rust
-    /// use assoc::AssocExt;
+    /// use assoc::AssocStrictExt;
     ///
-    /// let map = vec![(1, "a"), (2, "b")];
-    /// let keys: Vec<i32> = map.into_keys().collect();
-    /// assert_eq!(keys, [1, 2]);
+    /// assert!(vec![("a", 1), ("a", 2)].has_duplicate_keys());
     /// ```
-    fn into_keys(self) -> IntoKeys<K, V>;
+    fn has_duplicate_keys(&self) -> bool;
 
-    /// Get an iterator over the values of the map.
+    /// Get an iterator over the keys that occur more than once in the vector.
     ///
     /// ```rust
-    /// use assoc::AssocExt;
-    ///
-    /// let map = vec![("a", 1), ("b", 2)];
-    /// let mut iter = map.values();
+    /// use assoc::AssocStrictExt;
     ///
-    /// assert_eq!(iter.next().unwrap(), &1);
-    /// assert_eq!(iter.next().unwrap(), &2);
-    /// assert_eq!(iter.next(), None);
+    /// let map = vec![("a", 1), ("b", 2), ("a", 3)];
+    /// assert_eq!(map.duplicate_keys().collect::<Vec<_>>(), [&"a"]);
     /// ```
-    fn values(&self) -> Values<'_, K, V>;
+    fn duplicate_keys(&self) -> DuplicateKeys<'_, K, V>;
 
-    /// Get a mutable iterator over the values of the map.
+    /// Remove pairs with duplicate keys, keeping either the first or the last pair with each key.
     ///
     /// ```rust
-    /// use assoc::AssocExt;
+    /// use assoc::vec::KeepPolicy;
+    /// use assoc::AssocStrictExt;
     ///
-    /// let mut map = vec![(1, "a".to_string()), (2, "b".to_string())];
+    /// let mut map = vec![("a", 1), ("b", 2), ("a", 3)];
+    /// map.dedup_keys(KeepPolicy::Last);
+    /// assert_eq!(map, [("b", 2), ("a", 3)]);
+    /// ```
+    fn dedup_keys(&mut self, policy: KeepPolicy);
+
+    /// Convert the map into a [`HashMap`], keeping the first pair with each key.
     ///
-    /// for value in map.values_mut() {
-    ///     value.push_str("!");
-    /// }
+    /// ```rust
+    /// use assoc::AssocStrictExt;
     ///
-    /// let values: Vec<String> = map.values().cloned().collect();
-    /// assert_eq!(values, ["a!".to_string(), "b!".to_string()]);
+    /// let map = vec![("a", 1), ("a", 2)].into_hash_map();
+    /// assert_eq!(map["a"], 1);
     /// ```
-    fn values_mut(&mut self) -> ValuesMut<'_, K, V>;
+    #[cfg(feature = "std")]
+    fn into_hash_map(self) -> HashMap<K, V>
+    where
+        K: Hash;
 
-    /// Create a consuming iterator visiting all the values of the map.
+    /// Convert the map into a [`BTreeMap`], keeping the first pair with each key.
     ///
     /// ```rust
-    /// use assoc::AssocExt;
+    /// use assoc::AssocStrictExt;
     ///
-    /// let map = vec![(1, "a"), (2, "b")];
-    /// let values: Vec<&str> = map.into_values().collect();
-    /// assert_eq!(values, ["a", "b"]);
+    /// let map = vec![("a", 1), ("a", 2)].into_btree_map();
+    /// assert_eq!(map["a"], 1);
     /// ```
-    fn into_values(self) -> IntoValues<K, V>;
+    fn into_btree_map(self) -> BTreeMap<K, V>
+    where
+        K: Ord;
+
+    fn pairs(&self) -> Iter<'_, K, V>;
+    fn pairs_mut(&mut self) -> IterMut<'_, K, V>;
+    fn keys(&self) -> Keys<'_, K, V>;
+    fn unique_keys(&self) -> UniqueKeys<'_, K, V>;
+    fn into_keys(self) -> IntoKeys<K, V, Self>;
+    fn values(&self) -> Values<'_, K, V>;
+    fn values_mut(&mut self) -> ValuesMut<'_, K, V>;
+    fn into_values(self) -> IntoValues<K, V, Self>;
 }
 
-impl<K, V> AssocExt<K, V> for Vec<(K, V)>
+impl<K, V, S> AssocStrictExt<K, V> for S
 where
-    K: PartialEq,
+    K: Eq,
+    S: AssocExt<K, V>,
 {
-    fn entry(&mut self, key: K) -> Entry<K, V> {
-        let found = self.iter_mut().enumerate().find(|(_, (k, _))| k == &key);
-        match found {
-            None => Entry::Vacant(VacantEntry::new(self, key)),
-            Some((index, _)) => Entry::Occupied(OccupiedEntry::new(self, key, index)),
-        }
+    fn entry(&mut self, key: K) -> Entry<'_, K, V, Self> {
+        debug_assert_unique_keys(self);
+        AssocExt::entry(self, key)
     }
 
     fn get<Q>(&self, key: &Q) -> Option<&V>
     where
-        K: Borrow<Q>,
-        Q: PartialEq + ?Sized,
+        Q: Equivalent<K> + ?Sized,
     {
-        self.iter().find(|(k, _)| k.borrow() == key).map(|(_, v)| v)
+        debug_assert_unique_keys(self);
+        AssocExt::get(self, key)
     }
 
     fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
     where
-        K: Borrow<Q>,
-        Q: PartialEq + ?Sized,
+        Q: Equivalent<K> + ?Sized,
+    {
+        debug_assert_unique_keys(self);
+        AssocExt::get_mut(self, key)
+    }
+
+    fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        Q: Equivalent<K> + ?Sized,
     {
-        self.iter_mut()
-            .find(|(k, _)| k.borrow() == key)
-            .map(|(_, v)| v)
+        debug_assert_unique_keys(self);
+        AssocExt::get_key_value(self, key)
+    }
+
+    fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        debug_assert_unique_keys(self);
+        AssocExt::contains_key(self, key)
     }
 
     fn insert(&mut self, key: K, value: V) -> Option<V> {
-        match self.entry(key) {
-            Entry::Occupied(mut entry) => Some(entry.insert(value)),
-            Entry::Vacant(entry) => {
-                entry.insert(value);
-                None
-            }
-        }
+        debug_assert_unique_keys(self);
+        AssocExt::insert(self, key, value)
     }
 
     fn remove<Q>(&mut self, key: &Q) -> Option<V>
     where
-        K: Borrow<Q>,
-        Q: PartialEq + ?Sized,
+        Q: Equivalent<K> + ?Sized,
     {
-        let found = self
-            .iter_mut()
-            .enumerate()
-            .find(|(_, (k, _))| k.borrow() == key);
-        match found {
-            None => None,
-            Some((index, _)) => {
-                let (_, v) = self.swap_remove(index);
-                Some(v)
-            }
-        }
+        debug_assert_unique_keys(self);
+        AssocExt::remove(self, key)
     }
 
-    fn keys(&self) -> Keys<'_, K, V> {
-        Keys { inner: self.iter() }
+    fn remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        debug_assert_unique_keys(self);
+        AssocExt::remove_entry(self, key)
     }
 
-    fn into_keys(self) -> IntoKeys<K, V> {
-        IntoKeys {
-            inner: self.into_iter(),
-        }
+    fn shift_remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        debug_assert_unique_keys(self);
+        AssocExt::shift_remove(self, key)
     }
 
-    fn values(&self) -> Values<'_, K, V> {
-        Values { inner: self.iter() }
+    fn shift_remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        debug_assert_unique_keys(self);
+        AssocExt::shift_remove_entry(self, key)
     }
 
-    fn values_mut(&mut self) -> ValuesMut<'_, K, V> {
-        ValuesMut {
-            inner: self.iter_mut(),
-        }
+    fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        AssocExt::retain(self, f)
     }
 
-    fn into_values(self) -> IntoValues<K, V> {
-        IntoValues {
-            inner: self.into_iter(),
-        }
+    fn drain(&mut self) -> Drain<'_, K, V, Self> {
+        AssocExt::drain(self)
     }
-}
 
-/// This has the same API as [`AssocExt`] but with the additional constraint `K: Eq`.
-///
-/// ```compile_fail
-/// use assoc::AssocStrictExt;
-///
-/// let map = vec![(1.0, 1), (2.0, 2)];
-/// map.entry(1.0);
-/// ```
-pub trait AssocStrictExt<K, V> {
-    /// Get a key's entry for in-place manipulation.
-    ///
-    /// ```rust
-    /// use assoc::AssocStrictExt;
-    ///
-    /// let mut count = Vec::new();
-    /// for x in vec!["a", "b", "c", "b"] {
-    ///     *count.entry(x).or_insert(0) += 1;
-    /// }
-    /// assert_eq!(count.get(&"b"), Some(&2));
-    /// ```
-    fn entry(&mut self, key: K) -> Entry<K, V>;
+    fn extract_if<F>(&mut self, pred: F) -> ExtractIf<'_, K, V, F, Self>
+    where
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        AssocExt::extract_if(self, pred)
+    }
 
-    /// Get a reference to the value associated with a key.
-    ///
-    /// ```rust
-    /// use assoc::AssocStrictExt;
-    ///
-    /// let map = vec![("a", 1), ("b", 2)];
-    /// assert_eq!(map.get(&"a"), Some(&1));
-    /// ```
-    fn get<Q>(&self, key: &Q) -> Option<&V>
+    fn get_by<P>(&self, pred: P) -> Option<&V>
     where
-        K: Borrow<Q>,
-        Q: PartialEq + ?Sized;
+        P: FnMut(&K) -> bool,
+    {
+        AssocExt::get_by(self, pred)
+    }
 
-    /// Get a mutable reference to the value associated with a key.
-    ///
-    /// ```rust
-    /// use assoc::AssocStrictExt;
-    ///
-    /// let mut map = vec![("a", 1), ("b", 2)];
-    /// *map.get_mut(&"a").unwrap() += 1;
-    /// assert_eq!(map.get(&"a"), Some(&2));
-    /// ```
-    fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    fn get_mut_by<P>(&mut self, pred: P) -> Option<&mut V>
     where
-        K: Borrow<Q>,
-        Q: PartialEq + ?Sized;
+        P: FnMut(&K) -> bool,
+    {
+        AssocExt::get_mut_by(self, pred)
+    }
 
-    /// Insert a key-value pair into the associative array.
-    /// If the map previously had the key, then the old value is returned. Otherwise, `None` is
-    /// returned.
-    ///
-    /// ```rust
-    /// use assoc::AssocStrictExt;
-    ///
-    /// let mut map = vec![("b", 3)];
-    /// assert_eq!(AssocStrictExt::insert(&mut map, "a", 1), None);
-    /// assert_eq!(AssocStrictExt::insert(&mut map, "a", 2), Some(1));
-    /// ```
-    fn insert(&mut self, key: K, value: V) -> Option<V>;
+    fn entry_by<P, F>(&mut self, pred: P, make_key: F) -> Entry<'_, K, V, Self>
+    where
+        P: FnMut(&K) -> bool,
+        F: FnOnce() -> K,
+    {
+        AssocExt::entry_by(self, pred, make_key)
+    }
 
-    /// Remove a key from the map, returning the value if it was previously in the map.
-    ///
-    /// ```rust
-    /// use assoc::AssocStrictExt;
-    ///
-    /// let mut map = vec![("a", 1)];
-    /// assert_eq!(AssocStrictExt::remove(&mut map, "a"), Some(1));
-    /// assert_eq!(AssocStrictExt::remove(&mut map, "a"), None);
-    /// ```
-    fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    fn entry_ref<'a, 'b, Q>(&'a mut self, key: &'b Q) -> EntryRef<'a, 'b, K, Q, V, Self>
     where
-        K: Borrow<Q>,
-        Q: PartialEq + ?Sized;
+        Q: Equivalent<K> + ToOwned<Owned = K> + ?Sized,
+    {
+        debug_assert_unique_keys(self);
+        AssocExt::entry_ref(self, key)
+    }
 
-    fn keys(&self) -> Keys<'_, K, V>;
-    fn into_keys(self) -> IntoKeys<K, V>;
-    fn values(&self) -> Values<'_, K, V>;
-    fn values_mut(&mut self) -> ValuesMut<'_, K, V>;
-    fn into_values(self) -> IntoValues<K, V>;
-}
+    fn first_key_value(&self) -> Option<(&K, &V)> {
+        AssocExt::first_key_value(self)
+    }
 
-impl<K, V> AssocStrictExt<K, V> for Vec<(K, V)>
-where
-    K: Eq,
-{
-    fn entry(&mut self, key: K) -> Entry<K, V> {
-        AssocExt::entry(self, key)
+    fn last_key_value(&self) -> Option<(&K, &V)> {
+        AssocExt::last_key_value(self)
     }
 
-    fn get<Q>(&self, key: &Q) -> Option<&V>
+    fn pop_first(&mut self) -> Option<(K, V)> {
+        AssocExt::pop_first(self)
+    }
+
+    fn pop_last(&mut self) -> Option<(K, V)> {
+        AssocExt::pop_last(self)
+    }
+
+    fn first_entry(&mut self) -> Option<OccupiedEntry<'_, K, V, Self>> {
+        AssocExt::first_entry(self)
+    }
+
+    fn last_entry(&mut self) -> Option<OccupiedEntry<'_, K, V, Self>> {
+        AssocExt::last_entry(self)
+    }
+
+    fn get_index(&self, index: usize) -> Option<(&K, &V)> {
+        AssocExt::get_index(self, index)
+    }
+
+    fn get_index_mut(&mut self, index: usize) -> Option<(&K, &mut V)> {
+        AssocExt::get_index_mut(self, index)
+    }
+
+    fn index_of<Q>(&self, key: &Q) -> Option<usize>
     where
-        K: Borrow<Q>,
-        Q: PartialEq + ?Sized,
+        Q: Equivalent<K> + ?Sized,
     {
-        AssocExt::get(self, key)
+        debug_assert_unique_keys(self);
+        AssocExt::index_of(self, key)
     }
 
-    fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    fn swap_indices(&mut self, a: usize, b: usize) {
+        AssocExt::swap_indices(self, a, b)
+    }
+
+    fn try_insert(&mut self, key: K, value: V) -> Result<&mut V, OccupiedError<'_, K, V, Self>> {
+        debug_assert_unique_keys(self);
+        AssocExt::try_insert(self, key, value)
+    }
+
+    fn insert_with<F>(&mut self, key: K, value: V, resolve: F) -> &mut V
     where
-        K: Borrow<Q>,
-        Q: PartialEq + ?Sized,
+        F: FnOnce(&mut V, V),
     {
-        AssocExt::get_mut(self, key)
+        debug_assert_unique_keys(self);
+        AssocExt::insert_with(self, key, value, resolve)
     }
 
-    fn insert(&mut self, key: K, value: V) -> Option<V> {
-        AssocExt::insert(self, key, value)
+    fn merge<I, F>(&mut self, other: I, resolve: F)
+    where
+        I: IntoIterator<Item = (K, V)>,
+        F: FnMut(&mut V, V),
+    {
+        AssocExt::merge(self, other, resolve)
     }
 
-    fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    fn extend_replace<I>(&mut self, iter: I) -> usize
     where
-        K: Borrow<Q>,
-        Q: PartialEq + ?Sized,
+        I: IntoIterator<Item = (K, V)>,
     {
-        AssocExt::remove(self, key)
+        debug_assert_unique_keys(self);
+        AssocExt::extend_replace(self, iter)
+    }
+
+    fn extend_keep<I>(&mut self, iter: I) -> usize
+    where
+        I: IntoIterator<Item = (K, V)>,
+    {
+        debug_assert_unique_keys(self);
+        AssocExt::extend_keep(self, iter)
+    }
+
+    fn difference<'a>(&'a self, other: &'a [(K, V)]) -> Difference<'a, K, V> {
+        AssocExt::difference(self, other)
+    }
+
+    fn intersection<'a>(&'a self, other: &'a [(K, V)]) -> Intersection<'a, K, V> {
+        AssocExt::intersection(self, other)
+    }
+
+    fn union<'a>(&'a self, other: &'a [(K, V)]) -> Union<'a, K, V> {
+        AssocExt::union(self, other)
+    }
+
+    fn symmetric_difference<'a>(&'a self, other: &'a [(K, V)]) -> SymmetricDifference<'a, K, V> {
+        AssocExt::symmetric_difference(self, other)
+    }
+
+    fn diff<'a>(&'a self, other: &'a [(K, V)]) -> Diff<'a, K, V> {
+        AssocExt::diff(self, other)
+    }
+
+    fn get_disjoint_mut<Q, const N: usize>(&mut self, keys: [&Q; N]) -> [Option<&mut V>; N]
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        debug_assert_unique_keys(self);
+        AssocExt::get_disjoint_mut(self, keys)
+    }
+
+    fn get_promote<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        debug_assert_unique_keys(self);
+        AssocExt::get_promote(self, key)
+    }
+
+    fn get_touch<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        debug_assert_unique_keys(self);
+        AssocExt::get_touch(self, key)
+    }
+
+    fn insert_bounded(
+        &mut self,
+        key: K,
+        value: V,
+        max_len: usize,
+        policy: EvictPolicy,
+    ) -> Option<(K, V)> {
+        debug_assert_unique_keys(self);
+        AssocExt::insert_bounded(self, key, value, max_len, policy)
+    }
+
+    fn cursor_mut(&mut self) -> CursorMut<'_, K, V, Self> {
+        debug_assert_unique_keys(self);
+        AssocExt::cursor_mut(self)
+    }
+
+    fn has_duplicate_keys(&self) -> bool {
+        AssocExt::has_duplicate_keys(self)
+    }
+
+    fn duplicate_keys(&self) -> DuplicateKeys<'_, K, V> {
+        AssocExt::duplicate_keys(self)
+    }
+
+    fn dedup_keys(&mut self, policy: KeepPolicy) {
+        AssocExt::dedup_keys(self, policy)
+    }
+
+    #[cfg(feature = "std")]
+    fn into_hash_map(self) -> HashMap<K, V>
+    where
+        K: Hash,
+    {
+        AssocExt::into_hash_map(self)
+    }
+
+    fn into_btree_map(self) -> BTreeMap<K, V>
+    where
+        K: Ord,
+    {
+        AssocExt::into_btree_map(self)
+    }
+
+    fn pairs(&self) -> Iter<'_, K, V> {
+        AssocExt::pairs(self)
+    }
+
+    fn pairs_mut(&mut self) -> IterMut<'_, K, V> {
+        AssocExt::pairs_mut(self)
     }
 
     fn keys(&self) -> Keys<'_, K, V> {
         AssocExt::keys(self)
     }
 
-    fn into_keys(self) -> IntoKeys<K, V> {
+    fn unique_keys(&self) -> UniqueKeys<'_, K, V> {
+        AssocExt::unique_keys(self)
+    }
+
+    fn into_keys(self) -> IntoKeys<K, V, Self> {
         AssocExt::into_keys(self)
     }
 
@@ -508,7 +3400,7 @@ where
         AssocExt::values_mut(self)
     }
 
-    fn into_values(self) -> IntoValues<K, V> {
+    fn into_values(self) -> IntoValues<K, V, Self> {
         AssocExt::into_values(self)
     }
 }
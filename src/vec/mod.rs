@@ -1,6 +1,31 @@
 //! See the [module level documentation for an overview](crate).
+mod collect;
+mod cursor;
 mod entry;
 mod ext;
+mod multi;
+#[cfg(feature = "rayon")]
+mod par;
+mod slice;
+mod sorted;
+mod storage;
 
-pub use entry::{Entry, OccupiedEntry, VacantEntry};
-pub use ext::{AssocExt, AssocStrictExt};
+#[cfg(feature = "std")]
+pub use collect::from_hash_map;
+pub use collect::{
+    from_btree_map, from_iter_first_wins, from_iter_last_wins, group, try_from_iter,
+    DuplicateKeyError,
+};
+pub use cursor::CursorMut;
+pub use entry::{Entry, EntryRef, OccupiedEntry, OccupiedError, VacantEntry, VacantEntryRef};
+pub use ext::{
+    AssocExt, AssocStrictExt, Changed, Diff, Difference, Drain, DuplicateKeys, EvictPolicy,
+    ExtractIf, Intersection, IntoKeys, IntoValues, Iter, IterMut, KeepPolicy, Keys,
+    SymmetricDifference, Union, UniqueKeys, Values, ValuesMut,
+};
+pub use multi::{AssocMultiExt, GetAll, GetAllMut};
+#[cfg(feature = "rayon")]
+pub use par::{AssocParExt, ParIter, ParKeys, ParValues, ParValuesMut};
+pub use slice::AssocSliceExt;
+pub use sorted::AssocSortedExt;
+pub use storage::Storage;
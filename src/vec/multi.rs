@@ -0,0 +1,217 @@
+use alloc::vec::Vec;
+use core::fmt;
+use core::slice;
+
+use crate::{AssocExt, Equivalent};
+
+/// An iterator over the values associated with a key in a multimap.
+///
+/// Returned by [`AssocMultiExt::get_all`].
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct GetAll<'a, 'q, K: 'a, V: 'a, Q: ?Sized> {
+    inner: slice::Iter<'a, (K, V)>,
+    key: &'q Q,
+}
+
+impl<'a, K, V, Q> Iterator for GetAll<'a, '_, K, V, Q>
+where
+    Q: Equivalent<K> + ?Sized,
+{
+    type Item = &'a V;
+
+    fn next(&mut self) -> Option<&'a V> {
+        let key = self.key;
+        self.inner.find(|(k, _)| key.equivalent(k)).map(|(_, v)| v)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.inner.size_hint().1)
+    }
+}
+
+impl<K, V: fmt::Debug, Q> fmt::Debug for GetAll<'_, '_, K, V, Q>
+where
+    Q: Equivalent<K> + ?Sized,
+{
+    /// ```rust
+    /// use assoc::AssocMultiExt;
+    ///
+    /// let map = vec![("a", 1), ("b", 2), ("a", 3)];
+    /// assert_eq!(format!("{:?}", map.get_all(&"a")), "[1, 3]");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+impl<K, V, Q: ?Sized> Clone for GetAll<'_, '_, K, V, Q> {
+    fn clone(&self) -> Self {
+        GetAll {
+            inner: self.inner.clone(),
+            key: self.key,
+        }
+    }
+}
+
+/// A mutable iterator over the values associated with a key in a multimap.
+///
+/// Returned by [`AssocMultiExt::get_all_mut`].
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct GetAllMut<'a, 'q, K: 'a, V: 'a, Q: ?Sized> {
+    inner: slice::IterMut<'a, (K, V)>,
+    key: &'q Q,
+}
+
+impl<'a, K, V, Q> Iterator for GetAllMut<'a, '_, K, V, Q>
+where
+    Q: Equivalent<K> + ?Sized,
+{
+    type Item = &'a mut V;
+
+    fn next(&mut self) -> Option<&'a mut V> {
+        let key = self.key;
+        self.inner.find(|(k, _)| key.equivalent(k)).map(|(_, v)| v)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.inner.size_hint().1)
+    }
+}
+
+impl<K, V: fmt::Debug, Q> fmt::Debug for GetAllMut<'_, '_, K, V, Q>
+where
+    Q: Equivalent<K> + ?Sized,
+{
+    /// ```rust
+    /// use assoc::AssocMultiExt;
+    ///
+    /// let mut map = vec![("a", 1), ("b", 2), ("a", 3)];
+    /// assert_eq!(format!("{:?}", map.get_all_mut(&"a")), "[1, 3]");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let key = self.key;
+        f.debug_list()
+            .entries(
+                self.inner
+                    .as_slice()
+                    .iter()
+                    .filter(|(k, _)| key.equivalent(k))
+                    .map(|(_, v)| v),
+            )
+            .finish()
+    }
+}
+
+/// A trait extension that allows vectors to be treated as multimaps, where a key may be
+/// associated with several values.
+///
+/// This is useful for data such as HTTP headers, where the same key can legitimately appear more
+/// than once and the relative order of the pairs matters.
+///
+/// ```rust
+/// use assoc::AssocMultiExt;
+///
+/// let mut headers = Vec::new();
+/// headers.insert_multi("Accept", "text/html");
+/// headers.insert_multi("Host", "example.com");
+/// headers.insert_multi("Accept", "application/json");
+///
+/// let accept: Vec<&str> = headers.get_all(&"Accept").copied().collect();
+/// assert_eq!(accept, ["text/html", "application/json"]);
+/// ```
+pub trait AssocMultiExt<K, V> {
+    /// Get an iterator over all the values associated with a key, in the order they appear in the
+    /// vector.
+    ///
+    /// ```rust
+    /// use assoc::AssocMultiExt;
+    ///
+    /// let map = vec![("a", 1), ("b", 2), ("a", 3)];
+    /// let values: Vec<i32> = map.get_all(&"a").copied().collect();
+    /// assert_eq!(values, [1, 3]);
+    /// assert_eq!(map.get_all(&"c").next(), None);
+    /// ```
+    fn get_all<'a, 'q, Q>(&'a self, key: &'q Q) -> GetAll<'a, 'q, K, V, Q>
+    where
+        Q: Equivalent<K> + ?Sized;
+
+    /// Get a mutable iterator over all the values associated with a key, in the order they appear
+    /// in the vector.
+    ///
+    /// ```rust
+    /// use assoc::AssocMultiExt;
+    ///
+    /// let mut map = vec![("a", 1), ("b", 2), ("a", 3)];
+    /// for v in map.get_all_mut(&"a") {
+    ///     *v *= 10;
+    /// }
+    /// assert_eq!(map, [("a", 10), ("b", 2), ("a", 30)]);
+    /// ```
+    fn get_all_mut<'a, 'q, Q>(&'a mut self, key: &'q Q) -> GetAllMut<'a, 'q, K, V, Q>
+    where
+        Q: Equivalent<K> + ?Sized;
+
+    /// Append a key-value pair to the multimap without replacing existing values for the key.
+    ///
+    /// ```rust
+    /// use assoc::AssocMultiExt;
+    ///
+    /// let mut map = vec![("a", 1)];
+    /// map.insert_multi("a", 2);
+    /// assert_eq!(map, [("a", 1), ("a", 2)]);
+    /// ```
+    fn insert_multi(&mut self, key: K, value: V);
+
+    /// Remove all the pairs with a key, returning their values in the order they appeared.
+    /// The remaining pairs keep their relative order.
+    ///
+    /// ```rust
+    /// use assoc::AssocMultiExt;
+    ///
+    /// let mut map = vec![("a", 1), ("b", 2), ("a", 3)];
+    /// assert_eq!(map.remove_all(&"a"), [1, 3]);
+    /// assert_eq!(map, [("b", 2)]);
+    /// assert!(map.remove_all(&"a").is_empty());
+    /// ```
+    fn remove_all<Q>(&mut self, key: &Q) -> Vec<V>
+    where
+        Q: Equivalent<K> + ?Sized;
+}
+
+impl<K, V> AssocMultiExt<K, V> for Vec<(K, V)>
+where
+    K: PartialEq,
+{
+    fn get_all<'a, 'q, Q>(&'a self, key: &'q Q) -> GetAll<'a, 'q, K, V, Q>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        GetAll {
+            inner: self.as_slice().iter(),
+            key,
+        }
+    }
+
+    fn get_all_mut<'a, 'q, Q>(&'a mut self, key: &'q Q) -> GetAllMut<'a, 'q, K, V, Q>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        GetAllMut {
+            inner: self.as_mut_slice().iter_mut(),
+            key,
+        }
+    }
+
+    fn insert_multi(&mut self, key: K, value: V) {
+        self.push((key, value));
+    }
+
+    fn remove_all<Q>(&mut self, key: &Q) -> Vec<V>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        AssocExt::extract_if(self, |k, _| key.equivalent(k))
+            .map(|(_, v)| v)
+            .collect()
+    }
+}
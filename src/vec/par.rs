@@ -0,0 +1,220 @@
+use rayon::iter::plumbing::{Consumer, ProducerCallback, UnindexedConsumer};
+use rayon::iter::{
+    IndexedParallelIterator, IntoParallelRefIterator, IntoParallelRefMutIterator, Map,
+    ParallelIterator,
+};
+use rayon::slice;
+
+/// Implement [`ParallelIterator`] and [`IndexedParallelIterator`] for a wrapper around another
+/// indexed parallel iterator stored in its `inner` field.
+macro_rules! delegate_par_iter {
+    ($name:ident<$a:lifetime>, $item:ty, $($bounds:tt)*) => {
+        impl<$a, K: $a, V: $a> ParallelIterator for $name<$a, K, V>
+        where
+            $($bounds)*
+        {
+            type Item = $item;
+
+            fn drive_unindexed<C>(self, consumer: C) -> C::Result
+            where
+                C: UnindexedConsumer<Self::Item>,
+            {
+                self.inner.drive_unindexed(consumer)
+            }
+
+            fn opt_len(&self) -> Option<usize> {
+                self.inner.opt_len()
+            }
+        }
+
+        impl<$a, K: $a, V: $a> IndexedParallelIterator for $name<$a, K, V>
+        where
+            $($bounds)*
+        {
+            fn drive<C>(self, consumer: C) -> C::Result
+            where
+                C: Consumer<Self::Item>,
+            {
+                self.inner.drive(consumer)
+            }
+
+            fn len(&self) -> usize {
+                self.inner.len()
+            }
+
+            fn with_producer<CB>(self, callback: CB) -> CB::Output
+            where
+                CB: ProducerCallback<Self::Item>,
+            {
+                self.inner.with_producer(callback)
+            }
+        }
+    };
+}
+
+type PairMap<'a, K, V, R> = Map<slice::Iter<'a, (K, V)>, fn(&'a (K, V)) -> R>;
+type PairMapMut<'a, K, V, R> = Map<slice::IterMut<'a, (K, V)>, fn(&'a mut (K, V)) -> R>;
+
+/// A parallel iterator over the key-value pairs of an associative array.
+///
+/// Returned by [`AssocParExt::par_iter`].
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ParIter<'a, K: 'a, V: 'a> {
+    inner: PairMap<'a, K, V, (&'a K, &'a V)>,
+}
+
+delegate_par_iter!(ParIter<'a>, (&'a K, &'a V), K: Sync, V: Sync);
+
+/// A parallel iterator over the keys of an associative array.
+///
+/// Returned by [`AssocParExt::par_keys`].
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ParKeys<'a, K: 'a, V: 'a> {
+    inner: PairMap<'a, K, V, &'a K>,
+}
+
+delegate_par_iter!(ParKeys<'a>, &'a K, K: Sync, V: Sync);
+
+/// A parallel iterator over the values of an associative array.
+///
+/// Returned by [`AssocParExt::par_values`].
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ParValues<'a, K: 'a, V: 'a> {
+    inner: PairMap<'a, K, V, &'a V>,
+}
+
+delegate_par_iter!(ParValues<'a>, &'a V, K: Sync, V: Sync);
+
+/// A mutable parallel iterator over the values of an associative array.
+///
+/// Returned by [`AssocParExt::par_values_mut`].
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ParValuesMut<'a, K: 'a, V: 'a> {
+    inner: PairMapMut<'a, K, V, &'a mut V>,
+}
+
+delegate_par_iter!(ParValuesMut<'a>, &'a mut V, K: Send, V: Send);
+
+/// A trait extension that provides [rayon] parallel iterators over associative arrays.
+///
+/// Requires the `rayon` feature.
+/// The iterators visit every pair in the vector, including pairs with duplicate keys, and are
+/// indexed, so they support operations like [`IndexedParallelIterator::zip`].
+///
+/// [`AssocParExt::par_iter`] has the same name as rayon's
+/// [`IntoParallelRefIterator::par_iter`], which is in rayon's prelude and yields `&(K, V)`. If
+/// both traits are in scope, call it as `AssocParExt::par_iter(&map)`.
+///
+/// ```rust
+/// use assoc::vec::AssocParExt;
+/// use rayon::iter::ParallelIterator;
+///
+/// let mut map = vec![("a", 1), ("b", 2), ("c", 3)];
+/// map.par_values_mut().for_each(|v| *v *= 10);
+/// assert_eq!(map.par_values().sum::<i32>(), 60);
+/// ```
+pub trait AssocParExt<K, V> {
+    /// Get a parallel iterator over the key-value pairs of the map.
+    ///
+    /// ```rust
+    /// use assoc::vec::AssocParExt;
+    /// use rayon::iter::ParallelIterator;
+    ///
+    /// let map = vec![("a", 1), ("b", 2)];
+    /// let pairs: Vec<(&&str, &i32)> = map.par_iter().collect();
+    /// assert_eq!(pairs, [(&"a", &1), (&"b", &2)]);
+    /// ```
+    fn par_iter(&self) -> ParIter<'_, K, V>
+    where
+        K: Sync,
+        V: Sync;
+
+    /// Get a parallel iterator over the keys of the map.
+    ///
+    /// ```rust
+    /// use assoc::vec::AssocParExt;
+    /// use rayon::iter::ParallelIterator;
+    ///
+    /// let map = vec![("a", 1), ("b", 2)];
+    /// assert!(map.par_keys().any(|k| *k == "b"));
+    /// ```
+    fn par_keys(&self) -> ParKeys<'_, K, V>
+    where
+        K: Sync,
+        V: Sync;
+
+    /// Get a parallel iterator over the values of the map.
+    ///
+    /// ```rust
+    /// use assoc::vec::AssocParExt;
+    /// use rayon::iter::ParallelIterator;
+    ///
+    /// let map = vec![("a", 1), ("b", 2)];
+    /// assert_eq!(map.par_values().max(), Some(&2));
+    /// ```
+    fn par_values(&self) -> ParValues<'_, K, V>
+    where
+        K: Sync,
+        V: Sync;
+
+    /// Get a parallel iterator over mutable references to the values of the map.
+    ///
+    /// ```rust
+    /// use assoc::vec::AssocParExt;
+    /// use rayon::iter::ParallelIterator;
+    ///
+    /// let mut map = vec![("a", 1), ("b", 2)];
+    /// map.par_values_mut().for_each(|v| *v += 1);
+    /// assert_eq!(map, [("a", 2), ("b", 3)]);
+    /// ```
+    fn par_values_mut(&mut self) -> ParValuesMut<'_, K, V>
+    where
+        K: Send,
+        V: Send;
+}
+
+impl<K, V> AssocParExt<K, V> for [(K, V)] {
+    fn par_iter(&self) -> ParIter<'_, K, V>
+    where
+        K: Sync,
+        V: Sync,
+    {
+        let pair: fn(&(K, V)) -> (&K, &V) = |(k, v)| (k, v);
+        ParIter {
+            inner: IntoParallelRefIterator::par_iter(self).map(pair),
+        }
+    }
+
+    fn par_keys(&self) -> ParKeys<'_, K, V>
+    where
+        K: Sync,
+        V: Sync,
+    {
+        let key: fn(&(K, V)) -> &K = |(k, _)| k;
+        ParKeys {
+            inner: IntoParallelRefIterator::par_iter(self).map(key),
+        }
+    }
+
+    fn par_values(&self) -> ParValues<'_, K, V>
+    where
+        K: Sync,
+        V: Sync,
+    {
+        let value: fn(&(K, V)) -> &V = |(_, v)| v;
+        ParValues {
+            inner: IntoParallelRefIterator::par_iter(self).map(value),
+        }
+    }
+
+    fn par_values_mut(&mut self) -> ParValuesMut<'_, K, V>
+    where
+        K: Send,
+        V: Send,
+    {
+        let value: fn(&mut (K, V)) -> &mut V = |(_, v)| v;
+        ParValuesMut {
+            inner: IntoParallelRefMutIterator::par_iter_mut(self).map(value),
+        }
+    }
+}
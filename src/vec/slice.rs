@@ -0,0 +1,157 @@
+use crate::vec::{Keys, Values};
+use crate::Equivalent;
+
+/// A trait extension that provides read-only associative array operations on slices and arrays
+/// of pairs.
+///
+/// This is useful for lookup tables that are declared as arrays and never need to grow.
+///
+/// ```rust
+/// use assoc::AssocSliceExt;
+///
+/// const TABLE: [(&str, u32); 3] = [("one", 1), ("two", 2), ("three", 3)];
+/// assert_eq!(TABLE.get(&"two"), Some(&2));
+/// assert!(!TABLE.contains_key(&"four"));
+/// ```
+///
+/// Slices have an inherent `get` method that takes an index, which takes precedence over
+/// [`AssocSliceExt::get`] when calling `get` on a `&[(K, V)]`.
+/// Use the fully qualified form in that case:
+///
+/// ```rust
+/// use assoc::AssocSliceExt;
+///
+/// let table: &[(&str, u32)] = &[("one", 1), ("two", 2)];
+/// assert_eq!(AssocSliceExt::get(table, &"one"), Some(&1));
+/// ```
+pub trait AssocSliceExt<K, V> {
+    /// Get a reference to the value associated with a key.
+    ///
+    /// ```rust
+    /// use assoc::AssocSliceExt;
+    ///
+    /// let map = [("a", 1), ("b", 2)];
+    /// assert_eq!(map.get(&"a"), Some(&1));
+    /// assert_eq!(map.get(&"c"), None);
+    /// ```
+    fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        Q: Equivalent<K> + ?Sized;
+
+    /// Get the key-value pair associated with a key.
+    ///
+    /// ```rust
+    /// use assoc::AssocSliceExt;
+    ///
+    /// let map = [("a", 1), ("b", 2)];
+    /// assert_eq!(map.get_key_value(&"b"), Some((&"b", &2)));
+    /// ```
+    fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        Q: Equivalent<K> + ?Sized;
+
+    /// Check whether the map contains a value for a key.
+    ///
+    /// ```rust
+    /// use assoc::AssocSliceExt;
+    ///
+    /// let map = [("a", 1), ("b", 2)];
+    /// assert!(map.contains_key(&"a"));
+    /// assert!(!map.contains_key(&"c"));
+    /// ```
+    fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        Q: Equivalent<K> + ?Sized;
+
+    /// Get an iterator over the keys of the map.
+    ///
+    /// ```rust
+    /// use assoc::AssocSliceExt;
+    ///
+    /// let map = [("a", 1), ("b", 2)];
+    /// let keys: Vec<&str> = map.keys().copied().collect();
+    /// assert_eq!(keys, ["a", "b"]);
+    /// ```
+    fn keys(&self) -> Keys<'_, K, V>;
+
+    /// Get an iterator over the values of the map.
+    ///
+    /// ```rust
+    /// use assoc::AssocSliceExt;
+    ///
+    /// let map = [("a", 1), ("b", 2)];
+    /// let values: Vec<i32> = map.values().copied().collect();
+    /// assert_eq!(values, [1, 2]);
+    /// ```
+    fn values(&self) -> Values<'_, K, V>;
+}
+
+impl<K, V> AssocSliceExt<K, V> for [(K, V)]
+where
+    K: PartialEq,
+{
+    fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        self.iter().find(|(k, _)| key.equivalent(k)).map(|(_, v)| v)
+    }
+
+    fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        self.iter()
+            .find(|(k, _)| key.equivalent(k))
+            .map(|(k, v)| (k, v))
+    }
+
+    fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        self.iter().any(|(k, _)| key.equivalent(k))
+    }
+
+    fn keys(&self) -> Keys<'_, K, V> {
+        Keys::new(self.iter())
+    }
+
+    fn values(&self) -> Values<'_, K, V> {
+        Values::new(self.iter())
+    }
+}
+
+impl<K, V, const N: usize> AssocSliceExt<K, V> for [(K, V); N]
+where
+    K: PartialEq,
+{
+    fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        AssocSliceExt::get(self.as_slice(), key)
+    }
+
+    fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        AssocSliceExt::get_key_value(self.as_slice(), key)
+    }
+
+    fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        AssocSliceExt::contains_key(self.as_slice(), key)
+    }
+
+    fn keys(&self) -> Keys<'_, K, V> {
+        AssocSliceExt::keys(self.as_slice())
+    }
+
+    fn values(&self) -> Values<'_, K, V> {
+        AssocSliceExt::values(self.as_slice())
+    }
+}
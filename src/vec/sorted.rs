@@ -0,0 +1,229 @@
+use alloc::vec::Vec;
+use core::borrow::Borrow;
+
+use crate::vec::{Entry, OccupiedEntry, VacantEntry};
+
+/// A trait extension for vectors that are kept sorted by key.
+///
+/// Lookups use binary search, so they take `O(log N)` comparisons instead of the `O(N)`
+/// equality checks performed by [`AssocExt`][crate::AssocExt].
+/// Insertions and removals shift the following pairs to keep the vector sorted, so they still take
+/// linear time, but the memory layout is the same plain `Vec<(K, V)>`.
+///
+/// The methods assume that the vector is sorted by key and contains no duplicate keys, which
+/// holds if it is only modified through this trait.
+/// An existing vector can be prepared with `vec.sort_by(|(a, _), (b, _)| a.cmp(b))` followed by
+/// `vec.dedup_by(|(a, _), (b, _)| a == b)`.
+/// If the vector is not sorted, the results are unspecified but memory safe.
+///
+/// ```rust
+/// use assoc::AssocSortedExt;
+///
+/// let mut map = Vec::new();
+/// AssocSortedExt::insert(&mut map, 3, "c");
+/// AssocSortedExt::insert(&mut map, 1, "a");
+/// AssocSortedExt::insert(&mut map, 2, "b");
+/// assert_eq!(map, [(1, "a"), (2, "b"), (3, "c")]);
+/// assert_eq!(map.get(&2), Some(&"b"));
+/// ```
+pub trait AssocSortedExt<K, V> {
+    /// Get a key's entry for in-place manipulation.
+    /// Inserting into a vacant entry places the pair at its sorted position, and removing an
+    /// occupied entry preserves the order of the remaining pairs.
+    ///
+    /// ```rust
+    /// use assoc::AssocSortedExt;
+    ///
+    /// let mut count = Vec::new();
+    /// for x in vec!["c", "a", "b", "a"] {
+    ///     *count.entry(x).or_insert(0) += 1;
+    /// }
+    /// assert_eq!(count, [("a", 2), ("b", 1), ("c", 1)]);
+    /// ```
+    fn entry(&mut self, key: K) -> Entry<'_, K, V>;
+
+    /// Get a reference to the value associated with a key.
+    ///
+    /// ```rust
+    /// use assoc::AssocSortedExt;
+    ///
+    /// let map = vec![("a", 1), ("b", 2)];
+    /// assert_eq!(map.get(&"b"), Some(&2));
+    /// assert_eq!(map.get(&"c"), None);
+    /// ```
+    fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized;
+
+    /// Get a mutable reference to the value associated with a key.
+    ///
+    /// ```rust
+    /// use assoc::AssocSortedExt;
+    ///
+    /// let mut map = vec![("a", 1), ("b", 2)];
+    /// *map.get_mut(&"a").unwrap() += 1;
+    /// assert_eq!(map.get(&"a"), Some(&2));
+    /// ```
+    fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized;
+
+    /// Get the key-value pair associated with a key.
+    ///
+    /// ```rust
+    /// use assoc::AssocSortedExt;
+    ///
+    /// let map = vec![("a", 1), ("b", 2)];
+    /// assert_eq!(map.get_key_value(&"b"), Some((&"b", &2)));
+    /// ```
+    fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized;
+
+    /// Check whether the map contains a value for a key.
+    ///
+    /// ```rust
+    /// use assoc::AssocSortedExt;
+    ///
+    /// let map = vec![("a", 1), ("b", 2)];
+    /// assert!(map.contains_key(&"a"));
+    /// assert!(!map.contains_key(&"c"));
+    /// ```
+    fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized;
+
+    /// Insert a key-value pair at its sorted position.
+    /// If the map previously had the key, then the old value is returned. Otherwise, `None` is
+    /// returned.
+    ///
+    /// ```rust
+    /// use assoc::AssocSortedExt;
+    ///
+    /// let mut map = vec![("a", 1), ("c", 3)];
+    /// assert_eq!(AssocSortedExt::insert(&mut map, "b", 2), None);
+    /// assert_eq!(AssocSortedExt::insert(&mut map, "b", 4), Some(2));
+    /// assert_eq!(map, [("a", 1), ("b", 4), ("c", 3)]);
+    /// ```
+    fn insert(&mut self, key: K, value: V) -> Option<V>;
+
+    /// Remove a key from the map, returning the value if it was previously in the map.
+    /// The remaining pairs stay sorted.
+    ///
+    /// ```rust
+    /// use assoc::AssocSortedExt;
+    ///
+    /// let mut map = vec![("a", 1), ("b", 2), ("c", 3)];
+    /// assert_eq!(AssocSortedExt::remove(&mut map, "a"), Some(1));
+    /// assert_eq!(AssocSortedExt::remove(&mut map, "a"), None);
+    /// assert_eq!(map, [("b", 2), ("c", 3)]);
+    /// ```
+    fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized;
+
+    /// Remove a key from the map, returning the stored key and value if the key was previously in
+    /// the map.
+    /// The remaining pairs stay sorted.
+    ///
+    /// ```rust
+    /// use assoc::AssocSortedExt;
+    ///
+    /// let mut map = vec![("a", 1), ("b", 2)];
+    /// assert_eq!(map.remove_entry(&"a"), Some(("a", 1)));
+    /// assert_eq!(map, [("b", 2)]);
+    /// ```
+    fn remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized;
+}
+
+impl<K, V> AssocSortedExt<K, V> for Vec<(K, V)>
+where
+    K: Ord,
+{
+    fn entry(&mut self, key: K) -> Entry<'_, K, V> {
+        match self.binary_search_by(|(k, _)| k.cmp(&key)) {
+            Ok(index) => Entry::Occupied(OccupiedEntry::new(self, index).preserving_order()),
+            Err(index) => Entry::Vacant(VacantEntry::new_at(self, key, index).preserving_order()),
+        }
+    }
+
+    fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        AssocSortedExt::get_key_value(self, key).map(|(_, v)| v)
+    }
+
+    fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let index = search(self, key).ok()?;
+        let (_, v) = &mut self[index];
+        Some(v)
+    }
+
+    fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let index = search(self, key).ok()?;
+        let (k, v) = &self[index];
+        Some((k, v))
+    }
+
+    fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        search(self, key).is_ok()
+    }
+
+    fn insert(&mut self, key: K, value: V) -> Option<V> {
+        match AssocSortedExt::entry(self, key) {
+            Entry::Occupied(mut entry) => Some(entry.insert(value)),
+            Entry::Vacant(entry) => {
+                entry.insert(value);
+                None
+            }
+        }
+    }
+
+    fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        AssocSortedExt::remove_entry(self, key).map(|(_, v)| v)
+    }
+
+    fn remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let index = search(self, key).ok()?;
+        Some(Vec::remove(self, index))
+    }
+}
+
+/// Binary search a sorted slice of pairs for a key.
+fn search<K, V, Q>(slice: &[(K, V)], key: &Q) -> Result<usize, usize>
+where
+    K: Borrow<Q>,
+    Q: Ord + ?Sized,
+{
+    slice.binary_search_by(|(k, _)| k.borrow().cmp(key))
+}
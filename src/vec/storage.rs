@@ -0,0 +1,212 @@
+use alloc::vec::{self, Vec};
+
+/// A contiguous, growable sequence that can back an associative array.
+///
+/// The entry types and owning iterators are generic over the storage.
+/// [`AssocExt`][crate::AssocExt] and [`AssocStrictExt`][crate::AssocStrictExt] are implemented
+/// for [`Vec<(K, V)>`][Vec], and with the `smallvec` and `arrayvec` features enabled, for
+/// `SmallVec<[(K, V); N]>` and `ArrayVec<(K, V), N>`, so small maps can live entirely on the stack.
+///
+/// The methods have the same semantics as the [`Vec`] methods of the same name.
+pub trait Storage<T>: IntoIterator<Item = T> + Sized {
+    /// The iterator returned by [`Storage::drain`].
+    type Drain<'a>: Iterator<Item = T>
+    where
+        Self: 'a;
+
+    /// Extract a slice containing the entire sequence.
+    fn as_slice(&self) -> &[T];
+
+    /// Extract a mutable slice containing the entire sequence.
+    fn as_mut_slice(&mut self) -> &mut [T];
+
+    /// Insert an element at position `index`, shifting all elements after it to the right.
+    fn insert(&mut self, index: usize, element: T);
+
+    /// Reserve capacity for at least `additional` more elements. Storage with a fixed capacity
+    /// may ignore this.
+    fn reserve(&mut self, additional: usize);
+
+    /// Remove and return the element at position `index`, shifting all elements after it to the
+    /// left.
+    fn remove(&mut self, index: usize) -> T;
+
+    /// Remove and return the element at position `index`, replacing it with the last element.
+    fn swap_remove(&mut self, index: usize) -> T;
+
+    /// Remove the last element and return it, or `None` if the sequence is empty.
+    fn pop(&mut self) -> Option<T>;
+
+    /// Retain only the elements specified by the predicate.
+    fn retain_mut<F>(&mut self, f: F)
+    where
+        F: FnMut(&mut T) -> bool;
+
+    /// Remove all the elements, returning them in an iterator.
+    fn drain(&mut self) -> Self::Drain<'_>;
+}
+
+impl<T> Storage<T> for Vec<T> {
+    type Drain<'a>
+        = vec::Drain<'a, T>
+    where
+        Self: 'a;
+
+    fn as_slice(&self) -> &[T] {
+        self
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        self
+    }
+
+    fn insert(&mut self, index: usize, element: T) {
+        Vec::insert(self, index, element);
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        Vec::reserve(self, additional);
+    }
+
+    fn remove(&mut self, index: usize) -> T {
+        Vec::remove(self, index)
+    }
+
+    fn swap_remove(&mut self, index: usize) -> T {
+        Vec::swap_remove(self, index)
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        Vec::pop(self)
+    }
+
+    fn retain_mut<F>(&mut self, f: F)
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        Vec::retain_mut(self, f);
+    }
+
+    fn drain(&mut self) -> Self::Drain<'_> {
+        Vec::drain(self, ..)
+    }
+}
+
+/// Requires the `smallvec` feature.
+///
+/// ```rust
+/// use assoc::AssocExt;
+/// use smallvec::SmallVec;
+///
+/// let mut map: SmallVec<[(&str, i32); 4]> = SmallVec::new();
+/// map.entry("a").or_insert(1);
+/// *map.entry("a").or_insert(0) += 1;
+/// assert_eq!(map.get(&"a"), Some(&2));
+/// assert!(!map.spilled());
+/// ```
+#[cfg(feature = "smallvec")]
+impl<A: smallvec::Array> Storage<A::Item> for smallvec::SmallVec<A> {
+    type Drain<'a>
+        = smallvec::Drain<'a, A>
+    where
+        Self: 'a;
+
+    fn as_slice(&self) -> &[A::Item] {
+        self
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [A::Item] {
+        self
+    }
+
+    fn insert(&mut self, index: usize, element: A::Item) {
+        smallvec::SmallVec::insert(self, index, element);
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        smallvec::SmallVec::reserve(self, additional);
+    }
+
+    fn remove(&mut self, index: usize) -> A::Item {
+        smallvec::SmallVec::remove(self, index)
+    }
+
+    fn swap_remove(&mut self, index: usize) -> A::Item {
+        smallvec::SmallVec::swap_remove(self, index)
+    }
+
+    fn pop(&mut self) -> Option<A::Item> {
+        smallvec::SmallVec::pop(self)
+    }
+
+    fn retain_mut<F>(&mut self, f: F)
+    where
+        F: FnMut(&mut A::Item) -> bool,
+    {
+        smallvec::SmallVec::retain(self, f);
+    }
+
+    fn drain(&mut self) -> Self::Drain<'_> {
+        smallvec::SmallVec::drain(self, ..)
+    }
+}
+
+/// Requires the `arrayvec` feature.
+///
+/// Inserting a new key into a full `ArrayVec` panics, like
+/// [`ArrayVec::insert`][arrayvec::ArrayVec::insert].
+///
+/// ```rust
+/// use arrayvec::ArrayVec;
+/// use assoc::AssocExt;
+///
+/// let mut map: ArrayVec<(&str, i32), 2> = ArrayVec::new();
+/// map.entry("a").or_insert(1);
+/// map.entry("b").or_insert(2);
+/// assert_eq!(AssocExt::remove(&mut map, &"a"), Some(1));
+/// assert_eq!(map.as_slice(), [("b", 2)]);
+/// ```
+#[cfg(feature = "arrayvec")]
+impl<T, const CAP: usize> Storage<T> for arrayvec::ArrayVec<T, CAP> {
+    type Drain<'a>
+        = arrayvec::Drain<'a, T, CAP>
+    where
+        Self: 'a;
+
+    fn as_slice(&self) -> &[T] {
+        self
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        self
+    }
+
+    fn insert(&mut self, index: usize, element: T) {
+        arrayvec::ArrayVec::insert(self, index, element);
+    }
+
+    fn reserve(&mut self, _additional: usize) {}
+
+    fn remove(&mut self, index: usize) -> T {
+        arrayvec::ArrayVec::remove(self, index)
+    }
+
+    fn swap_remove(&mut self, index: usize) -> T {
+        arrayvec::ArrayVec::swap_remove(self, index)
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        arrayvec::ArrayVec::pop(self)
+    }
+
+    fn retain_mut<F>(&mut self, f: F)
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        arrayvec::ArrayVec::retain(self, f);
+    }
+
+    fn drain(&mut self) -> Self::Drain<'_> {
+        arrayvec::ArrayVec::drain(self, ..)
+    }
+}